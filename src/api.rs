@@ -5,6 +5,8 @@ use hyper::client::HttpConnector;
 use hyper::Body;
 use error::*;
 use hyper::rt::{Future, Stream};
+use futures::stream;
+use futures::future::{self, Either};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use hyper::Request;
@@ -12,13 +14,14 @@ use serde_json;
 use responses::*;
 use requests::*;
 use std::str;
-use stream::UpdatesStream;
+use stream::{AlbumStream, UpdatesStream};
 use std::collections::VecDeque;
 use std::time::Duration;
 use std::time::Instant;
-use tokio::timer::Delay;
+use tokio::timer::{Delay, Interval};
 use try_from::TryFrom;
 use std::cmp::max;
+use multipart;
 
 const BASE_API_URI: &'static str = "https://api.telegram.org/bot";
 const GET_FILE_URI: &'static str = "https://api.telegram.org/file/bot";
@@ -82,6 +85,12 @@ impl BotApiClient {
         }
     }
 
+    /// Same as `incoming_updates`, but messages of one media group are emitted as a single album
+    /// once no new items arrived for `quiet_window`. The order of updates is kept, so updates received after an album wait for it.
+    pub fn incoming_albums(&self, request: GetUpdatesRequest, quiet_window: Duration) -> impl Stream<Item=AlbumUpdate, Error=Error> {
+        AlbumStream::new(self.incoming_updates(request), quiet_window)
+    }
+
     pub fn download_file(&self, request: &GetFileRequest, timeout: Duration) -> impl Future<Item=Vec<u8>, Error=Error> {
         let cloned_self = self.clone();
         let download_future =
@@ -112,6 +121,67 @@ impl BotApiClient {
         self.send_request(request, <Message as TryFrom<raw::message::Message>>::try_from, timeout)
     }
 
+    pub fn send_messages(&self, requests: Vec<SendMessageRequest>, timeout: Duration) -> impl Future<Item=Vec<Message>, Error=Error> {
+        let cloned_self = self.clone();
+        stream::iter_ok(requests)
+            .and_then(move |request| cloned_self.send_message(&request, timeout))
+            .collect()
+    }
+
+    pub fn forward_message(&self, request: &ForwardMessageRequest, timeout: Duration) -> impl Future<Item=Message, Error=Error> {
+        self.send_request(request, <Message as TryFrom<raw::message::Message>>::try_from, timeout)
+    }
+
+    pub fn forward_messages(&self, request: &ForwardMessagesRequest, timeout: Duration) -> impl Future<Item=Vec<MessageId>, Error=Error> {
+        self.send_request(request, Ok, timeout)
+    }
+
+    pub fn copy_message(&self, request: &CopyMessageRequest, timeout: Duration) -> impl Future<Item=MessageId, Error=Error> {
+        self.send_request(request, Ok, timeout)
+    }
+
+    pub fn copy_messages(&self, request: &CopyMessagesRequest, timeout: Duration) -> impl Future<Item=Vec<MessageId>, Error=Error> {
+        self.send_request(request, Ok, timeout)
+    }
+
+    pub fn edit_message_text(&self, request: &EditMessageTextRequest, timeout: Duration) -> impl Future<Item=EditedMessage, Error=Error> {
+        self.send_request(request, <EditedMessage as TryFrom<raw::message::EditResult>>::try_from, timeout)
+    }
+
+    pub fn edit_message_caption(&self, request: &EditMessageCaptionRequest, timeout: Duration) -> impl Future<Item=EditedMessage, Error=Error> {
+        self.send_request(request, <EditedMessage as TryFrom<raw::message::EditResult>>::try_from, timeout)
+    }
+
+    pub fn edit_message_media(&self, request: &EditMessageMediaRequest, timeout: Duration) -> impl Future<Item=EditedMessage, Error=Error> {
+        self.send_request(request, <EditedMessage as TryFrom<raw::message::EditResult>>::try_from, timeout)
+    }
+
+    pub fn edit_message_reply_markup(&self, request: &EditMessageReplyMarkupRequest, timeout: Duration) -> impl Future<Item=EditedMessage, Error=Error> {
+        self.send_request(request, <EditedMessage as TryFrom<raw::message::EditResult>>::try_from, timeout)
+    }
+
+    pub fn delete_message(&self, request: &DeleteMessageRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn delete_messages(&self, request: &DeleteMessagesRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    /// Deletes any number of messages chunk by chunk, resolves to `true` only if every chunk was deleted.
+    pub fn delete_all_messages(&self, chat_id: ChatId, message_ids: &[i64], timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        let cloned_self = self.clone();
+        stream::iter_ok(DeleteMessagesRequest::chunks(chat_id, message_ids))
+            .and_then(move |request| cloned_self.delete_messages(&request, timeout))
+            .fold(true, |all_deleted, deleted| Ok::<_, Error>(all_deleted && deleted))
+    }
+
     pub fn send_chat_action(&self, request: &SendChatAction, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
         fn id(val: bool) -> Result<bool, UnexpectedResponse> {
             Ok(val)
@@ -119,6 +189,26 @@ impl BotApiClient {
         self.send_request(request, id, timeout)
     }
 
+    /// Sends the chat action every `CHAT_ACTION_INTERVAL` until `fut` completes.
+    /// Failures of the action requests are ignored, the result of `fut` is returned as is.
+    pub fn keep_chat_action<F: Future>(&self, request: SendChatAction, fut: F, timeout: Duration) -> impl Future<Item=F::Item, Error=F::Error> {
+        let cloned_self = self.clone();
+        let actions =
+            Interval::new(Instant::now(), CHAT_ACTION_INTERVAL)
+                .map_err(|_| ())
+                .for_each(move |_| cloned_self.send_chat_action(&request, timeout).then(|_| Ok(())));
+        fut.select2(actions).then(|x| {
+            match x {
+                Ok(Either::A((item, _))) =>
+                    Either::A(future::ok(item)),
+                Err(Either::A((err, _))) =>
+                    Either::A(future::err(err)),
+                Ok(Either::B((_, fut))) | Err(Either::B((_, fut))) =>
+                    Either::B(fut)
+            }
+        })
+    }
+
     pub fn answer_callback_query(&self, request: &AnswerCallbackQuery, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
         fn id(val: bool) -> Result<bool, UnexpectedResponse> {
             Ok(val)
@@ -126,6 +216,13 @@ impl BotApiClient {
         self.send_request(request, id, timeout)
     }
 
+    pub fn answer_inline_query(&self, request: &AnswerInlineQuery, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
     pub fn send_media_group(&self, request: &SendMediaGroupRequest, timeout: Duration) -> impl Future<Item=Vec<Message>, Error=Error> {
         fn map(x: Vec<raw::message::Message>) -> Result<Vec<Message>, UnexpectedResponse> {
             x.into_iter().map(TryFrom::try_from).collect()
@@ -137,6 +234,263 @@ impl BotApiClient {
         self.send_request(&GetMe, Ok, timeout)
     }
 
+    pub fn get_chat(&self, request: &GetChatRequest, timeout: Duration) -> impl Future<Item=ChatFullInfo, Error=Error> {
+        self.send_request(request, <ChatFullInfo as TryFrom<raw::chat::Chat>>::try_from, timeout)
+    }
+
+    pub fn get_chat_member(&self, request: &GetChatMemberRequest, timeout: Duration) -> impl Future<Item=ChatMember, Error=Error> {
+        self.send_request(request, <ChatMember as TryFrom<raw::chat_member::ChatMember>>::try_from, timeout)
+    }
+
+    pub fn get_chat_administrators(&self, request: &GetChatAdministratorsRequest, timeout: Duration) -> impl Future<Item=Vec<ChatMember>, Error=Error> {
+        fn map(members: Vec<raw::chat_member::ChatMember>) -> Result<Vec<ChatMember>, UnexpectedResponse> {
+            members.into_iter().map(TryFrom::try_from).collect()
+        }
+        self.send_request(request, map, timeout)
+    }
+
+    pub fn get_chat_member_count(&self, request: &GetChatMemberCountRequest, timeout: Duration) -> impl Future<Item=i64, Error=Error> {
+        fn id(val: i64) -> Result<i64, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn ban_chat_member(&self, request: &BanChatMemberRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn unban_chat_member(&self, request: &UnbanChatMemberRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn restrict_chat_member(&self, request: &RestrictChatMemberRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_chat_permissions(&self, request: &SetChatPermissionsRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn promote_chat_member(&self, request: &PromoteChatMemberRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_chat_administrator_custom_title(&self, request: &SetChatAdministratorCustomTitleRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_my_default_administrator_rights(&self, request: &SetMyDefaultAdministratorRightsRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn get_my_default_administrator_rights(&self, request: &GetMyDefaultAdministratorRightsRequest, timeout: Duration) -> impl Future<Item=ChatAdministratorRights, Error=Error> {
+        self.send_request(request, Ok, timeout)
+    }
+
+    /// Removes the user from the chat without banning: bans and immediately unbans them.
+    pub fn kick_chat_member(&self, chat_id: ChatId, user_id: i64, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        let cloned_self = self.clone();
+        let unban = UnbanChatMemberRequest::new(chat_id.clone(), user_id);
+        self.ban_chat_member(&BanChatMemberRequest::new(chat_id, user_id), timeout)
+            .and_then(move |_| cloned_self.unban_chat_member(&unban, timeout))
+    }
+
+    pub fn set_chat_title(&self, request: &SetChatTitleRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_chat_description(&self, request: &SetChatDescriptionRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_chat_photo(&self, request: &SetChatPhotoRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn delete_chat_photo(&self, request: &DeleteChatPhotoRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn pin_chat_message(&self, request: &PinChatMessageRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn unpin_chat_message(&self, request: &UnpinChatMessageRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn unpin_all_chat_messages(&self, request: &UnpinAllChatMessagesRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn leave_chat(&self, request: &LeaveChatRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_chat_sticker_set(&self, request: &SetChatStickerSetRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn delete_chat_sticker_set(&self, request: &DeleteChatStickerSetRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn export_chat_invite_link(&self, request: &ExportChatInviteLinkRequest, timeout: Duration) -> impl Future<Item=String, Error=Error> {
+        self.send_request(request, Ok, timeout)
+    }
+
+    pub fn create_chat_invite_link(&self, request: &CreateChatInviteLinkRequest, timeout: Duration) -> impl Future<Item=ChatInviteLink, Error=Error> {
+        self.send_request(request, <ChatInviteLink as TryFrom<raw::chat::ChatInviteLink>>::try_from, timeout)
+    }
+
+    pub fn edit_chat_invite_link(&self, request: &EditChatInviteLinkRequest, timeout: Duration) -> impl Future<Item=ChatInviteLink, Error=Error> {
+        self.send_request(request, <ChatInviteLink as TryFrom<raw::chat::ChatInviteLink>>::try_from, timeout)
+    }
+
+    pub fn revoke_chat_invite_link(&self, request: &RevokeChatInviteLinkRequest, timeout: Duration) -> impl Future<Item=ChatInviteLink, Error=Error> {
+        self.send_request(request, <ChatInviteLink as TryFrom<raw::chat::ChatInviteLink>>::try_from, timeout)
+    }
+
+    pub fn approve_chat_join_request(&self, request: &ApproveChatJoinRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn decline_chat_join_request(&self, request: &DeclineChatJoinRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn create_forum_topic(&self, request: &CreateForumTopicRequest, timeout: Duration) -> impl Future<Item=ForumTopic, Error=Error> {
+        self.send_request(request, Ok, timeout)
+    }
+
+    pub fn edit_forum_topic(&self, request: &EditForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn close_forum_topic(&self, request: &CloseForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn reopen_forum_topic(&self, request: &ReopenForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn delete_forum_topic(&self, request: &DeleteForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn edit_general_forum_topic(&self, request: &EditGeneralForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn close_general_forum_topic(&self, request: &CloseGeneralForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn reopen_general_forum_topic(&self, request: &ReopenGeneralForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn hide_general_forum_topic(&self, request: &HideGeneralForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn unhide_general_forum_topic(&self, request: &UnhideGeneralForumTopicRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
+    pub fn set_message_reaction(&self, request: &SetMessageReactionRequest, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        fn id(val: bool) -> Result<bool, UnexpectedResponse> {
+            Ok(val)
+        }
+        self.send_request(request, id, timeout)
+    }
+
     pub fn get_file(&self, request: &GetFileRequest, timeout: Duration) -> impl Future<Item=File, Error=Error> {
         self.send_request(request, Ok, timeout)
     }
@@ -158,11 +512,19 @@ impl BotApiClient {
               TResult: DeserializeOwned,
     {
         let uri = format!("{}{}/{}", BASE_API_URI, self.token, request.method());
+        let files = request.files();
         let request =
-            Request::post(uri)
-                .header("content-type", "application/json")
-                .body(Body::from(serde_json::to_string(request).expect("Error while serializing request")))
-                .expect("While creating request an error has occurred");
+            if files.is_empty() {
+                Request::post(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(request).expect("Error while serializing request")))
+            } else {
+                let multipart = multipart::encode(serde_json::to_value(request).expect("Error while serializing request"), &files);
+                Request::post(uri)
+                    .header("content-type", format!("multipart/form-data; boundary={}", multipart.boundary).as_str())
+                    .body(Body::from(multipart.body))
+            }
+            .expect("While creating request an error has occurred");
 
 
         let api_request = self.http_client.request(request)
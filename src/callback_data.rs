@@ -0,0 +1,187 @@
+use std::cmp::max;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use error::CallbackDataError;
+
+pub const MAX_CALLBACK_DATA_LENGTH: usize = 64;
+pub const DEFAULT_CALLBACK_DATA_STORE_CAPACITY: usize = 10_000;
+
+// Encoded values are compact json, which never starts with this char, so stored keys can't be confused with them.
+const STORED_KEY_PREFIX: char = '#';
+
+/// Keeps callback payloads that do not fit into 64 bytes on the bot side.
+/// `store` returns a short key which is sent to telegram instead of the payload.
+pub trait CallbackDataStore {
+    fn store(&self, data: String) -> String;
+    fn load(&self, key: &str) -> Option<String>;
+}
+
+/// Keeps at most `capacity` payloads, the least recently stored or loaded ones are forgotten first
+/// and their buttons fail with `CallbackDataError::NotStored`.
+/// Keys are derived from the payload, so identical payloads share an entry and keys issued before a restart never point to other data.
+pub struct InMemoryCallbackDataStore {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+struct Entries {
+    data: HashMap<String, String>,
+    order: VecDeque<String>,
+}
+
+impl InMemoryCallbackDataStore {
+    pub fn new() -> InMemoryCallbackDataStore {
+        InMemoryCallbackDataStore::with_capacity(DEFAULT_CALLBACK_DATA_STORE_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> InMemoryCallbackDataStore {
+        InMemoryCallbackDataStore {
+            capacity: max(capacity, 1),
+            entries: Mutex::new(Entries {
+                data: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        }
+    }
+}
+
+impl Default for InMemoryCallbackDataStore {
+    fn default() -> Self {
+        InMemoryCallbackDataStore::new()
+    }
+}
+
+impl CallbackDataStore for InMemoryCallbackDataStore {
+    fn store(&self, data: String) -> String {
+        let mut entries = self.entries.lock().expect("Callback data store mutex is poisoned");
+        // Colliding hashes of different payloads are resolved by hashing again with the attempt number.
+        let mut attempt = 0u32;
+        let key =
+            loop {
+                let key = payload_key(&data, attempt);
+                match entries.data.get(&key) {
+                    Some(stored) if *stored != data =>
+                        attempt += 1,
+                    _ =>
+                        break key
+                }
+            };
+        if entries.data.contains_key(&key) {
+            entries.touch(&key);
+        } else {
+            if entries.order.len() >= self.capacity {
+                if let Some(oldest) = entries.order.pop_front() {
+                    entries.data.remove(&oldest);
+                }
+            }
+            entries.order.push_back(key.clone());
+            entries.data.insert(key.clone(), data);
+        }
+        key
+    }
+
+    fn load(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().expect("Callback data store mutex is poisoned");
+        let data = entries.data.get(key).cloned();
+        if data.is_some() {
+            entries.touch(key);
+        }
+        data
+    }
+}
+
+impl Entries {
+    fn touch(&mut self, key: &str) {
+        if let Some(index) = self.order.iter().position(|stored| stored == key) {
+            let key = self.order.remove(index).expect("Index is found in the queue");
+            self.order.push_back(key);
+        }
+    }
+}
+
+// `DefaultHasher::new` uses fixed keys, so the same payload gets the same key in every process built with the same compiler.
+fn payload_key(data: &str, attempt: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    attempt.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+pub fn encode<T: Serialize>(value: &T) -> Result<String, CallbackDataError> {
+    let data = serde_json::to_string(value)?;
+    check_length(data)
+}
+
+pub fn encode_with_store<T, S>(value: &T, store: &S) -> Result<String, CallbackDataError>
+    where T: Serialize,
+          S: CallbackDataStore + ?Sized {
+    let data = serde_json::to_string(value)?;
+    if data.len() <= MAX_CALLBACK_DATA_LENGTH {
+        return Ok(data);
+    }
+    let key = store.store(data);
+    check_length(format!("{}{}", STORED_KEY_PREFIX, key))
+}
+
+pub fn decode<T: DeserializeOwned>(data: &str) -> Result<T, CallbackDataError> {
+    serde_json::from_str(data).map_err(From::from)
+}
+
+pub fn decode_with_store<T, S>(data: &str, store: &S) -> Result<T, CallbackDataError>
+    where T: DeserializeOwned,
+          S: CallbackDataStore + ?Sized {
+    if data.starts_with(STORED_KEY_PREFIX) {
+        let key = &data[STORED_KEY_PREFIX.len_utf8()..];
+        match store.load(key) {
+            Some(stored) =>
+                decode(&stored),
+            None =>
+                Err(CallbackDataError::NotStored(String::from(key)))
+        }
+    } else {
+        decode(data)
+    }
+}
+
+fn check_length(data: String) -> Result<String, CallbackDataError> {
+    if data.len() > MAX_CALLBACK_DATA_LENGTH {
+        Err(CallbackDataError::TooLong { length: data.len() })
+    } else {
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_key_of_identical_payload() {
+        let store = InMemoryCallbackDataStore::new();
+
+        assert_eq!(store.store(String::from("a")), store.store(String::from("a")));
+    }
+
+    #[test]
+    fn evicts_least_recently_used_payload() {
+        let store = InMemoryCallbackDataStore::with_capacity(2);
+        let a = store.store(String::from("a"));
+        let b = store.store(String::from("b"));
+        store.store(String::from("a"));
+        let c = store.store(String::from("c"));
+        assert_eq!(store.load(&b), None);
+
+        store.load(&a);
+        store.store(String::from("d"));
+
+        assert_eq!(store.load(&c), None);
+        assert_eq!(store.load(&a), Some(String::from("a")));
+    }
+}
@@ -23,6 +23,20 @@ pub enum UnexpectedResponse {
     Unsupported,
 }
 
+#[derive(Debug)]
+pub enum CallbackDataError {
+    Serde(serde_json::Error),
+    TooLong { length: usize },
+    Missing,
+    NotStored(String),
+}
+
+#[derive(Debug)]
+pub enum KeyboardError {
+    TooManyButtonsInRow { row: usize, count: usize },
+    TooManyButtons { count: usize },
+}
+
 impl From<tokio::timer::Error> for Error {
     fn from(err: tokio::timer::Error) -> Self {
         Error::TokioTimer(err)
@@ -41,6 +55,12 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for CallbackDataError {
+    fn from(err: serde_json::Error) -> Self {
+        CallbackDataError::Serde(err)
+    }
+}
+
 impl error::Error for Error {
     fn cause(&self) -> Option<&error::Error> {
         match self {
@@ -83,4 +103,34 @@ impl fmt::Display for Error {
                 write!(f, "Unknown error has occurred: {}", s)
         }
     }
+}
+
+impl error::Error for CallbackDataError {}
+
+impl fmt::Display for CallbackDataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CallbackDataError::Serde(serde) =>
+                write!(f, "Serde error has occurred: {}", serde),
+            CallbackDataError::TooLong { length } =>
+                write!(f, "Callback data is {} bytes long, but telegram allows at most 64 bytes", length),
+            CallbackDataError::Missing =>
+                write!(f, "Callback query does not contain data"),
+            CallbackDataError::NotStored(key) =>
+                write!(f, "Callback data with key {} was not found in store", key),
+        }
+    }
+}
+
+impl error::Error for KeyboardError {}
+
+impl fmt::Display for KeyboardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyboardError::TooManyButtonsInRow { row, count } =>
+                write!(f, "Row {} contains {} buttons, but telegram allows at most 8 buttons in a row", row, count),
+            KeyboardError::TooManyButtons { count } =>
+                write!(f, "Keyboard contains {} buttons, but telegram allows at most 100 buttons", count),
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,152 @@
+use requests::{ParseMode, SendText};
+use super::escape::*;
+
+/// Builds message text for the given parse mode, escaping every piece of text passed to it.
+/// Legacy markdown has no syntax for underline, strikethrough and spoiler, such pieces are added as plain text.
+#[derive(Debug, Clone)]
+pub struct FormattedTextBuilder {
+    parse_mode: ParseMode,
+    text: String,
+}
+
+impl FormattedTextBuilder {
+    pub fn new(parse_mode: ParseMode) -> FormattedTextBuilder {
+        FormattedTextBuilder {
+            parse_mode,
+            text: String::new(),
+        }
+    }
+
+    pub fn text(self, text: &str) -> FormattedTextBuilder {
+        let escaped = escape(text, self.parse_mode);
+        self.push(escaped)
+    }
+
+    pub fn bold(self, text: &str) -> FormattedTextBuilder {
+        self.styled(text, "b", "*", "*")
+    }
+
+    pub fn italic(self, text: &str) -> FormattedTextBuilder {
+        self.styled(text, "i", "_", "_")
+    }
+
+    pub fn underline(self, text: &str) -> FormattedTextBuilder {
+        self.styled(text, "u", "__", "")
+    }
+
+    pub fn strikethrough(self, text: &str) -> FormattedTextBuilder {
+        self.styled(text, "s", "~", "")
+    }
+
+    pub fn spoiler(self, text: &str) -> FormattedTextBuilder {
+        self.styled(text, "tg-spoiler", "||", "")
+    }
+
+    pub fn code(self, code: &str) -> FormattedTextBuilder {
+        let formatted =
+            match self.parse_mode {
+                ParseMode::Html =>
+                    format!("<code>{}</code>", escape_html(code)),
+                ParseMode::Markdown =>
+                    wrap_markdown(code, "`"),
+                ParseMode::MarkdownV2 =>
+                    format!("`{}`", escape_markdown_v2_code(code)),
+            };
+        self.push(formatted)
+    }
+
+    pub fn pre(self, code: &str, language: Option<&str>) -> FormattedTextBuilder {
+        let formatted =
+            match (self.parse_mode, language) {
+                (ParseMode::Html, Some(language)) =>
+                    format!("<pre><code class=\"language-{}\">{}</code></pre>", escape_html(language), escape_html(code)),
+                (ParseMode::Html, None) =>
+                    format!("<pre>{}</pre>", escape_html(code)),
+                (ParseMode::Markdown, language) =>
+                    wrap_markdown(&format!("{}\n{}", language.unwrap_or(""), code), "```"),
+                (ParseMode::MarkdownV2, language) =>
+                    format!("```{}\n{}\n```", language.map(escape_markdown_v2_code).unwrap_or_default(), escape_markdown_v2_code(code)),
+            };
+        self.push(formatted)
+    }
+
+    pub fn link(self, text: &str, url: &str) -> FormattedTextBuilder {
+        let formatted =
+            match self.parse_mode {
+                ParseMode::Html =>
+                    format!("<a href=\"{}\">{}</a>", escape_html(url), escape_html(text)),
+                // Legacy markdown can't escape inside of a link, so the chars closing its parts are removed from the text and encoded in the url.
+                ParseMode::Markdown =>
+                    format!("[{}]({})", text.replace(']', ""), url.replace(')', "%29")),
+                ParseMode::MarkdownV2 =>
+                    format!("[{}]({})", escape_markdown_v2(text), escape_markdown_v2_url(url)),
+            };
+        self.push(formatted)
+    }
+
+    pub fn mention(self, text: &str, user_id: i64) -> FormattedTextBuilder {
+        self.link(text, &format!("tg://user?id={}", user_id))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn build(self) -> SendText {
+        SendText {
+            parse_mode: Some(self.parse_mode),
+            ..SendText::new(self.text)
+        }
+    }
+
+    fn styled(self, text: &str, html_tag: &str, markdown_v2_delimiter: &str, markdown_delimiter: &str) -> FormattedTextBuilder {
+        let formatted =
+            match self.parse_mode {
+                ParseMode::Html =>
+                    format!("<{}>{}</{}>", html_tag, escape_html(text), html_tag),
+                ParseMode::Markdown if markdown_delimiter.is_empty() =>
+                    escape_markdown(text),
+                ParseMode::Markdown =>
+                    wrap_markdown(text, markdown_delimiter),
+                ParseMode::MarkdownV2 =>
+                    format!("{}{}{}", markdown_v2_delimiter, escape_markdown_v2(text), markdown_v2_delimiter),
+            };
+        self.push(formatted)
+    }
+
+    fn push(mut self, formatted: String) -> FormattedTextBuilder {
+        match self.parse_mode {
+            ParseMode::MarkdownV2 =>
+                push_markdown_v2(&mut self.text, &formatted),
+            _ =>
+                self.text.push_str(&formatted),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_legacy_markdown_link_parseable() {
+        let builder = FormattedTextBuilder::new(ParseMode::Markdown).link("a]b", "http://x/(y)");
+
+        assert_eq!(builder.as_str(), "[ab](http://x/(y%29)");
+    }
+
+    #[test]
+    fn separates_underline_from_italic_ending_with_backslash() {
+        let builder = FormattedTextBuilder::new(ParseMode::MarkdownV2).italic("a\\").underline("b");
+
+        assert_eq!(builder.as_str(), "_a\\\\_\r__b__");
+    }
+
+    #[test]
+    fn does_not_separate_escaped_underscore() {
+        let builder = FormattedTextBuilder::new(ParseMode::MarkdownV2).text("a_").underline("b");
+
+        assert_eq!(builder.as_str(), "a\\___b__");
+    }
+}
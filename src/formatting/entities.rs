@@ -0,0 +1,64 @@
+use requests::{SendText, TextEntity, TextEntityKind};
+use requests::text_entity::utf16_len;
+
+/// Builds text together with its entities, so no parse mode and escaping is needed.
+#[derive(Debug, Clone)]
+pub struct EntityTextBuilder {
+    text: String,
+    entities: Vec<TextEntity>,
+    utf16_len: usize,
+}
+
+impl EntityTextBuilder {
+    pub fn new() -> EntityTextBuilder {
+        EntityTextBuilder {
+            text: String::new(),
+            entities: Vec::new(),
+            utf16_len: 0,
+        }
+    }
+
+    pub fn text(mut self, text: &str) -> EntityTextBuilder {
+        self.text.push_str(text);
+        self.utf16_len += utf16_len(text);
+        self
+    }
+
+    pub fn entity(self, text: &str, kind: TextEntityKind) -> EntityTextBuilder {
+        self.nested(kind, |builder| builder.text(text))
+    }
+
+    /// Applies `kind` to everything added by `build`, which allows to nest entities.
+    pub fn nested<F>(self, kind: TextEntityKind, build: F) -> EntityTextBuilder
+        where F: FnOnce(EntityTextBuilder) -> EntityTextBuilder {
+        let offset = self.utf16_len;
+        let mut builder = build(self);
+        let length = builder.utf16_len - offset;
+        if length > 0 {
+            builder.entities.push(TextEntity { kind, offset, length });
+        }
+        builder
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_parts(self) -> (String, Vec<TextEntity>) {
+        (self.text, self.entities)
+    }
+
+    pub fn build(self) -> SendText {
+        let (text, entities) = self.into_parts();
+        SendText {
+            entities: Some(entities),
+            ..SendText::new(text)
+        }
+    }
+}
+
+impl Default for EntityTextBuilder {
+    fn default() -> Self {
+        EntityTextBuilder::new()
+    }
+}
@@ -0,0 +1,71 @@
+use requests::ParseMode;
+
+pub fn escape(text: &str, parse_mode: ParseMode) -> String {
+    match parse_mode {
+        ParseMode::Html =>
+            escape_html(text),
+        ParseMode::Markdown =>
+            escape_markdown(text),
+        ParseMode::MarkdownV2 =>
+            escape_markdown_v2(text),
+    }
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn escape_markdown(text: &str) -> String {
+    escape_with(text, |c| matches!(c, '_' | '*' | '`' | '['))
+}
+
+pub fn escape_markdown_v2(text: &str) -> String {
+    escape_with(text, |c| matches!(c, '_' | '*' | '[' | ']' | '(' | ')' | '~' | '`' | '>' | '#' | '+' | '-' | '=' | '|' | '{' | '}' | '.' | '!' | '\\'))
+}
+
+pub(crate) fn escape_markdown_v2_code(text: &str) -> String {
+    escape_with(text, |c| c == '`' || c == '\\')
+}
+
+pub(crate) fn escape_markdown_v2_url(text: &str) -> String {
+    escape_with(text, |c| c == ')' || c == '\\')
+}
+
+// Legacy markdown doesn't allow escaping inside of an entity, so the entity is closed around each delimiter char.
+pub(crate) fn wrap_markdown(text: &str, delimiter: &str) -> String {
+    let escaped_delimiter = format!("{}\\{}{}", delimiter, delimiter, delimiter);
+    format!("{}{}{}", delimiter, text.replace(delimiter, &escaped_delimiter), delimiter)
+}
+
+// Markdown v2 treats `__` greedily as underline, so adjacent italic and underline delimiters are separated with \r as telegram suggests.
+// The trailing `_` is a delimiter unless it is escaped, i.e. preceded by an odd number of backslashes.
+pub(crate) fn push_markdown_v2(text: &mut String, piece: &str) {
+    if piece.starts_with('_') && text.ends_with('_') {
+        let backslashes = text[..text.len() - 1].chars().rev().take_while(|&c| c == '\\').count();
+        if backslashes % 2 == 0 {
+            text.push('\r');
+        }
+    }
+    text.push_str(piece);
+}
+
+fn escape_with<F: Fn(char) -> bool>(text: &str, should_escape: F) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if should_escape(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,11 @@
+pub use self::escape::{escape, escape_html, escape_markdown, escape_markdown_v2};
+pub use self::builder::*;
+pub use self::render::*;
+pub use self::entities::*;
+pub use self::split::*;
+
+mod escape;
+mod builder;
+mod render;
+mod entities;
+mod split;
@@ -0,0 +1,271 @@
+use std::cmp::min;
+
+use responses::{MessageEntity, MessageEntityKind};
+use super::escape::*;
+
+struct Tag {
+    open: String,
+    close: String,
+    code: bool,
+    quote: bool,
+}
+
+struct Span {
+    start: usize,
+    end: usize,
+    tag: Tag,
+}
+
+/// Converts received text and its entities back to html markup.
+pub fn to_html(text: &str, entities: &[MessageEntity]) -> String {
+    render(text, entities, html_tag, |text, _, _| escape_html(text), |output, piece| output.push_str(piece))
+}
+
+/// Converts received text and its entities back to markdown v2 markup.
+pub fn to_markdown_v2(text: &str, entities: &[MessageEntity]) -> String {
+    fn escape_text(text: &str, code: bool, quote: bool) -> String {
+        let escaped = if code { escape_markdown_v2_code(text) } else { escape_markdown_v2(text) };
+        if quote { escaped.replace('\n', "\n>") } else { escaped }
+    }
+    render(text, entities, markdown_v2_tag, escape_text, push_markdown_v2)
+}
+
+fn html_tag(kind: &MessageEntityKind) -> Option<Tag> {
+    let tag =
+        match kind {
+            MessageEntityKind::Bold =>
+                Tag::new("<b>", "</b>"),
+            MessageEntityKind::Italic =>
+                Tag::new("<i>", "</i>"),
+            MessageEntityKind::Underline =>
+                Tag::new("<u>", "</u>"),
+            MessageEntityKind::Strikethrough =>
+                Tag::new("<s>", "</s>"),
+            MessageEntityKind::Spoiler =>
+                Tag::new("<tg-spoiler>", "</tg-spoiler>"),
+            MessageEntityKind::Blockquote =>
+                Tag::new("<blockquote>", "</blockquote>"),
+            MessageEntityKind::ExpandableBlockquote =>
+                Tag::new("<blockquote expandable>", "</blockquote>"),
+            MessageEntityKind::Code =>
+                Tag::code("<code>", "</code>"),
+            MessageEntityKind::Pre { language: Some(language) } =>
+                Tag::code(&format!("<pre><code class=\"language-{}\">", escape_html(language)), "</code></pre>"),
+            MessageEntityKind::Pre { language: None } =>
+                Tag::code("<pre>", "</pre>"),
+            MessageEntityKind::TextLink { url } =>
+                Tag::new(&format!("<a href=\"{}\">", escape_html(url)), "</a>"),
+            MessageEntityKind::TextMention { user } =>
+                Tag::new(&format!("<a href=\"tg://user?id={}\">", user.id), "</a>"),
+            MessageEntityKind::CustomEmoji { custom_emoji_id } =>
+                Tag::new(&format!("<tg-emoji emoji-id=\"{}\">", escape_html(custom_emoji_id)), "</tg-emoji>"),
+            _ =>
+                return None
+        };
+    Some(tag)
+}
+
+fn markdown_v2_tag(kind: &MessageEntityKind) -> Option<Tag> {
+    let tag =
+        match kind {
+            MessageEntityKind::Bold =>
+                Tag::new("*", "*"),
+            MessageEntityKind::Italic =>
+                Tag::new("_", "_"),
+            MessageEntityKind::Underline =>
+                Tag::new("__", "__"),
+            MessageEntityKind::Strikethrough =>
+                Tag::new("~", "~"),
+            MessageEntityKind::Spoiler =>
+                Tag::new("||", "||"),
+            MessageEntityKind::Blockquote =>
+                Tag::quote(">", ""),
+            MessageEntityKind::ExpandableBlockquote =>
+                Tag::quote("**>", "||"),
+            MessageEntityKind::Code =>
+                Tag::code("`", "`"),
+            MessageEntityKind::Pre { language } =>
+                Tag::code(&format!("```{}\n", language.as_ref().map(|x| escape_markdown_v2_code(x)).unwrap_or_default()), "\n```"),
+            MessageEntityKind::TextLink { url } =>
+                Tag::new("[", &format!("]({})", escape_markdown_v2_url(url))),
+            MessageEntityKind::TextMention { user } =>
+                Tag::new("[", &format!("](tg://user?id={})", user.id)),
+            MessageEntityKind::CustomEmoji { custom_emoji_id } =>
+                Tag::new("![", &format!("](tg://emoji?id={})", escape_markdown_v2_url(custom_emoji_id))),
+            _ =>
+                return None
+        };
+    Some(tag)
+}
+
+// Entities may overlap without nesting, while markup must be properly nested.
+// So on every boundary entities opened after the closing one are closed too and reopened right after it.
+fn render<T, E, P>(text: &str, entities: &[MessageEntity], tag: T, escape_text: E, push: P) -> String
+    where T: Fn(&MessageEntityKind) -> Option<Tag>,
+          E: Fn(&str, bool, bool) -> String,
+          P: Fn(&mut String, &str) {
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let mut spans: Vec<Span> =
+        entities.iter()
+            .filter_map(|entity| {
+                let start = min(entity.offset.max(0) as usize, utf16.len());
+                let end = min(start.saturating_add(entity.length.max(0) as usize), utf16.len());
+                tag(&entity.kind)
+                    .filter(|_| start < end)
+                    .map(|tag| Span { start, end, tag })
+            })
+            .collect();
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut boundaries: Vec<usize> = spans.iter().flat_map(|span| vec![span.start, span.end]).collect();
+    boundaries.push(0);
+    boundaries.push(utf16.len());
+    boundaries.sort();
+    boundaries.dedup();
+
+    let mut output = String::with_capacity(text.len());
+    let mut opened: Vec<usize> = Vec::new();
+    let mut next_span = 0;
+    for (i, &position) in boundaries.iter().enumerate() {
+        let mut reopen = Vec::new();
+        if let Some(first_closed) = opened.iter().position(|&span| spans[span].end <= position) {
+            while opened.len() > first_closed {
+                let span = opened.pop().expect("Opened span must exist");
+                push(&mut output, &spans[span].tag.close);
+                if spans[span].end > position {
+                    reopen.push(span);
+                }
+            }
+        }
+        while next_span < spans.len() && spans[next_span].start == position {
+            reopen.push(next_span);
+            next_span += 1;
+        }
+        reopen.sort_by(|&a, &b| spans[b].end.cmp(&spans[a].end).then(a.cmp(&b)));
+        for span in reopen {
+            push(&mut output, &spans[span].tag.open);
+            opened.push(span);
+        }
+
+        if let Some(&next_position) = boundaries.get(i + 1) {
+            let code = opened.iter().any(|&span| spans[span].tag.code);
+            let quote = opened.iter().any(|&span| spans[span].tag.quote);
+            let segment = String::from_utf16_lossy(&utf16[position..next_position]);
+            push(&mut output, &escape_text(&segment, code, quote));
+        }
+    }
+    output
+}
+
+impl Tag {
+    fn new(open: &str, close: &str) -> Tag {
+        Tag {
+            open: String::from(open),
+            close: String::from(close),
+            code: false,
+            quote: false,
+        }
+    }
+
+    fn code(open: &str, close: &str) -> Tag {
+        Tag {
+            code: true,
+            ..Tag::new(open, close)
+        }
+    }
+
+    fn quote(open: &str, close: &str) -> Tag {
+        Tag {
+            quote: true,
+            ..Tag::new(open, close)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(offset: i64, length: i64, kind: MessageEntityKind) -> MessageEntity {
+        MessageEntity {
+            offset,
+            length,
+            text: String::new(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn renders_nested_entities() {
+        let entities = [entity(0, 11, MessageEntityKind::Bold), entity(5, 6, MessageEntityKind::Italic)];
+
+        assert_eq!(to_html("bold italic", &entities), "<b>bold <i>italic</i></b>");
+        assert_eq!(to_markdown_v2("bold italic", &entities), "*bold _italic_*");
+    }
+
+    #[test]
+    fn reopens_overlapping_entities() {
+        let entities = [entity(0, 3, MessageEntityKind::Bold), entity(1, 3, MessageEntityKind::Italic)];
+
+        assert_eq!(to_html("abcd", &entities), "<b>a<i>bc</i></b><i>d</i>");
+        assert_eq!(to_markdown_v2("abcd", &entities), "*a_bc_*_d_");
+    }
+
+    #[test]
+    fn separates_adjacent_italic_and_underline() {
+        let entities = [entity(0, 2, MessageEntityKind::Italic), entity(2, 2, MessageEntityKind::Underline)];
+
+        assert_eq!(to_html("abcd", &entities), "<i>ab</i><u>cd</u>");
+        assert_eq!(to_markdown_v2("abcd", &entities), "_ab_\r__cd__");
+    }
+
+    #[test]
+    fn separates_underline_from_italic_ending_with_backslash() {
+        let entities = [entity(0, 2, MessageEntityKind::Italic), entity(2, 1, MessageEntityKind::Underline)];
+
+        assert_eq!(to_markdown_v2("a\\b", &entities), "_a\\\\_\r__b__");
+    }
+
+    #[test]
+    fn measures_offsets_in_utf16() {
+        let entities = [entity(0, 2, MessageEntityKind::Italic), entity(3, 4, MessageEntityKind::Bold)];
+
+        assert_eq!(to_html("😀 bold 😀", &entities), "<i>😀</i> <b>bold</b> 😀");
+        assert_eq!(to_markdown_v2("😀 bold 😀", &entities), "_😀_ *bold* 😀");
+    }
+
+    #[test]
+    fn escapes_code_only_as_code() {
+        let entities = [entity(2, 5, MessageEntityKind::Code)];
+
+        assert_eq!(to_html("1.<b>`\\", &entities), "1.<code>&lt;b&gt;`\\</code>");
+        assert_eq!(to_markdown_v2("1.<b>`\\", &entities), r"1\.`<b>\`\\`");
+    }
+
+    #[test]
+    fn renders_pre_with_language() {
+        let entities = [entity(0, 9, MessageEntityKind::Pre { language: Some(String::from("rust")) })];
+
+        assert_eq!(to_html("a < b * c", &entities), "<pre><code class=\"language-rust\">a &lt; b * c</code></pre>");
+        assert_eq!(to_markdown_v2("a < b * c", &entities), "```rust\na < b * c\n```");
+    }
+
+    #[test]
+    fn escapes_link_url() {
+        let entities = [entity(0, 4, MessageEntityKind::TextLink { url: String::from("http://x/(y)") })];
+
+        assert_eq!(to_html("link", &entities), "<a href=\"http://x/(y)\">link</a>");
+        assert_eq!(to_markdown_v2("link", &entities), r"[link](http://x/(y\))");
+    }
+
+    #[test]
+    fn prefixes_every_blockquote_line() {
+        let quote = [entity(0, 5, MessageEntityKind::Blockquote)];
+        let expandable = [entity(0, 5, MessageEntityKind::ExpandableBlockquote)];
+
+        assert_eq!(to_html("a\nb.c", &quote), "<blockquote>a\nb.c</blockquote>");
+        assert_eq!(to_markdown_v2("a\nb.c", &quote), ">a\n>b\\.c");
+        assert_eq!(to_html("a\nb.c", &expandable), "<blockquote expandable>a\nb.c</blockquote>");
+        assert_eq!(to_markdown_v2("a\nb.c", &expandable), "**>a\n>b\\.c||");
+    }
+}
@@ -0,0 +1,253 @@
+use std::cmp::{max, min};
+
+use requests::*;
+use requests::text_entity::utf16_len;
+
+pub const MAX_TEXT_LENGTH: usize = 4096;
+pub const MAX_CAPTION_LENGTH: usize = 1024;
+
+/// Splits text into parts of at most `limit` utf-16 code units, preferring paragraph, line and word boundaries.
+/// Parts are never cut inside of an entity unless a single entity is longer than `limit`.
+pub fn split_text(text: &str, entities: &[TextEntity], limit: usize) -> Vec<(String, Vec<TextEntity>)> {
+    let limit = max(limit, 2);
+    let mut boundaries: Vec<(usize, usize)> = Vec::with_capacity(text.len() + 1);
+    let mut position = 0;
+    for (index, c) in text.char_indices() {
+        boundaries.push((index, position));
+        position += c.len_utf16();
+    }
+    boundaries.push((text.len(), position));
+
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start + 1 < boundaries.len() {
+        let (start_index, start_position) = boundaries[start];
+        let end =
+            if position - start_position <= limit {
+                boundaries.len() - 1
+            } else {
+                find_cut(text, entities, &boundaries, start, limit)
+            };
+        let (end_index, end_position) = boundaries[end];
+        let part_entities =
+            entities.iter()
+                .filter_map(|entity| {
+                    let entity_start = max(entity.offset, start_position);
+                    let entity_end = min(entity.offset + entity.length, end_position);
+                    if entity_start < entity_end {
+                        Some(TextEntity {
+                            kind: entity.kind.clone(),
+                            offset: entity_start - start_position,
+                            length: entity_end - entity_start,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+        let part = &text[start_index..end_index];
+        if !part.trim().is_empty() {
+            parts.push((String::from(part), part_entities));
+        }
+        start = end;
+    }
+    parts
+}
+
+// Cuts are ranked paragraph > line > word > anything else, but only cuts in the second half of the window compete,
+// so that a paragraph break near the start doesn't produce a tiny part. Earlier cuts are used only if the second half has none.
+fn find_cut(text: &str, entities: &[TextEntity], boundaries: &[(usize, usize)], start: usize, limit: usize) -> usize {
+    let start_position = boundaries[start].1;
+    let mut best: Option<(u8, usize)> = None;
+    let mut best_near_end: Option<(u8, usize)> = None;
+    let mut last = start + 1;
+    let mut has_text = false;
+    for (candidate, &(index, position)) in boundaries.iter().enumerate().skip(start + 1) {
+        if position - start_position > limit {
+            break;
+        }
+        last = candidate;
+        let before = &text[..index];
+        let previous = before.chars().next_back().expect("Candidate is preceded by at least one char");
+        has_text = has_text || !previous.is_whitespace();
+        // Telegram rejects messages consisting of whitespaces only.
+        if !has_text {
+            continue;
+        }
+        if entities.iter().any(|entity| entity.offset < position && position < entity.offset + entity.length) {
+            continue;
+        }
+        let rank =
+            if before.ends_with("\n\n") { 3 }
+            else if previous == '\n' { 2 }
+            else if previous.is_whitespace() { 1 }
+            else { 0 };
+        let best =
+            if (position - start_position) * 2 >= limit { &mut best_near_end } else { &mut best };
+        match *best {
+            Some((best_rank, _)) if best_rank > rank => {}
+            _ => *best = Some((rank, candidate)),
+        }
+    }
+    best_near_end.or(best).map_or(last, |(_, candidate)| candidate)
+}
+
+/// Prepares requests for a text which may exceed telegram limits.
+/// The text is either split into several messages or, if it is longer than the document threshold, sent as a text file.
+#[derive(Debug, Clone)]
+pub struct LongText {
+    text: String,
+    entities: Vec<TextEntity>,
+    limit: usize,
+    document: Option<(usize, String)>,
+}
+
+impl LongText {
+    pub fn new(text: String, entities: Vec<TextEntity>) -> LongText {
+        LongText {
+            text,
+            entities,
+            limit: MAX_TEXT_LENGTH,
+            document: None,
+        }
+    }
+
+    pub fn limit(mut self, limit: usize) -> LongText {
+        self.limit = limit;
+        self
+    }
+
+    pub fn document_over(mut self, threshold: usize, file_name: String) -> LongText {
+        self.document = Some((threshold, file_name));
+        self
+    }
+
+    pub fn into_requests(self, chat_id: ChatId) -> Vec<SendMessageRequest> {
+        match self.document {
+            Some((threshold, name)) if utf16_len(&self.text) > threshold => {
+                let file = InputFile { name, data: self.text.into_bytes() };
+                vec![SendMessageRequest::new(chat_id, SendMessageKind::Document(SendDocument::new(FileKind::InputFile(file))))]
+            }
+            _ =>
+                split_text(&self.text, &self.entities, self.limit)
+                    .into_iter()
+                    .map(|(text, entities)| {
+                        let text = SendText {
+                            entities: if entities.is_empty() { None } else { Some(entities) },
+                            ..SendText::new(text)
+                        };
+                        SendMessageRequest::new(chat_id.clone(), SendMessageKind::Text(text))
+                    })
+                    .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(parts: Vec<(String, Vec<TextEntity>)>) -> Vec<String> {
+        parts.into_iter().map(|(text, _)| text).collect()
+    }
+
+    #[test]
+    fn prefers_paragraph_boundary() {
+        let parts = split_text("Hello world.\n\nSecond para here", &[], 20);
+
+        assert_eq!(texts(parts), vec!["Hello world.\n\n", "Second para here"]);
+    }
+
+    #[test]
+    fn prefers_line_boundary_to_word_boundary() {
+        let parts = split_text("one two\nthree four", &[], 14);
+
+        assert_eq!(texts(parts), vec!["one two\n", "three four"]);
+    }
+
+    #[test]
+    fn prefers_word_boundary_to_cutting_a_word() {
+        let parts = split_text("aaaa bbbb cccc", &[], 12);
+
+        assert_eq!(texts(parts), vec!["aaaa bbbb ", "cccc"]);
+    }
+
+    #[test]
+    fn ignores_paragraph_boundary_far_from_limit() {
+        let text = format!("Hi\n\n{}", "word ".repeat(20));
+
+        let parts = texts(split_text(&text, &[], 50));
+
+        assert_eq!(parts[0], format!("Hi\n\n{}", "word ".repeat(9)));
+        assert_eq!(parts.concat(), text);
+    }
+
+    #[test]
+    fn does_not_cut_entities() {
+        let text = "aaaa bbbb cccc dddd";
+        let entities = [TextEntity::new(text, 5..14, TextEntityKind::Bold)];
+
+        let parts = split_text(text, &entities, 12);
+
+        let summary: Vec<(String, Vec<(usize, usize)>)> =
+            parts.into_iter()
+                .map(|(text, entities)| (text, entities.iter().map(|entity| (entity.offset, entity.length)).collect()))
+                .collect();
+        assert_eq!(summary, vec![
+            (String::from("aaaa "), vec![]),
+            (String::from("bbbb cccc "), vec![(0, 9)]),
+            (String::from("dddd"), vec![]),
+        ]);
+    }
+
+    #[test]
+    fn does_not_cut_surrogate_pairs() {
+        let parts = split_text(&"😀".repeat(5), &[], 3);
+
+        assert_eq!(texts(parts), vec!["😀"; 5]);
+    }
+
+    #[test]
+    fn measures_entities_of_parts_in_utf16() {
+        let text = "😀 aaaa 😀 bbbb";
+        let entities = [TextEntity::new(text, text.rfind("bbbb").unwrap()..text.len(), TextEntityKind::Italic)];
+
+        let parts = split_text(text, &entities, 10);
+
+        assert_eq!(parts[1].0, "😀 bbbb");
+        assert_eq!((parts[1].1[0].offset, parts[1].1[0].length), (3, 4));
+    }
+
+    #[test]
+    fn sends_long_text_as_document() {
+        let requests = LongText::new("x".repeat(100), vec![])
+            .document_over(50, String::from("text.txt"))
+            .into_requests(ChatId::Id(1));
+
+        match requests.as_slice() {
+            [SendMessageRequest { kind: SendMessageKind::Document(SendDocument { document: FileKind::InputFile(file), .. }), .. }] => {
+                assert_eq!(file.name, "text.txt");
+                assert_eq!(file.data, "x".repeat(100).into_bytes());
+            }
+            requests =>
+                panic!("Unexpected requests: {:?}", requests)
+        }
+    }
+
+    #[test]
+    fn splits_text_under_document_threshold() {
+        let requests = LongText::new("aaaa bbbb cccc".to_string(), vec![])
+            .limit(12)
+            .document_over(50, String::from("text.txt"))
+            .into_requests(ChatId::Id(1));
+
+        let texts: Vec<&str> =
+            requests.iter()
+                .map(|request| match request.kind {
+                    SendMessageKind::Text(ref text) => text.text.as_str(),
+                    ref kind => panic!("Unexpected message kind: {:?}", kind),
+                })
+                .collect();
+        assert_eq!(texts, vec!["aaaa bbbb ", "cccc"]);
+    }
+}
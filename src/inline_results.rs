@@ -0,0 +1,188 @@
+use std::cmp::min;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use futures::Future;
+
+use api::BotApiClient;
+use error::Error;
+use requests::{AnswerInlineQuery, InlineQueryResult, MAX_INLINE_QUERY_RESULTS};
+use responses::{ChosenInlineResult, InlineQuery};
+
+/// Answers inline queries page by page from results cached per query text.
+/// `InlineQuery::offset` holds the generation of cached results and the index of the first result of the requested page,
+/// so every page comes from the same results as the first one, and an entry is kept while its pages are requested.
+pub struct InlineResults<F> {
+    produce: F,
+    ttl: Duration,
+    page_size: usize,
+    entries: Mutex<HashMap<String, Entry>>,
+    generation: AtomicUsize,
+    chosen: Mutex<HashMap<String, usize>>,
+}
+
+struct Entry {
+    generation: usize,
+    results: Arc<Vec<InlineQueryResult>>,
+    expires_at: Instant,
+}
+
+impl<F> InlineResults<F>
+    where F: Fn(&str) -> Vec<InlineQueryResult> {
+    /// `produce` is called with the query text once per `ttl`, users paging through or retyping the same query hit the cache.
+    pub fn new(ttl: Duration, produce: F) -> InlineResults<F> {
+        InlineResults {
+            produce,
+            ttl,
+            page_size: MAX_INLINE_QUERY_RESULTS,
+            entries: Mutex::new(HashMap::new()),
+            // Generations continue from the current time, so offsets issued before a restart don't match new results.
+            generation: AtomicUsize::new(SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_millis() as usize).unwrap_or_default()),
+            chosen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Page size is clamped to 1..=50, the number of results telegram accepts in one answer.
+    pub fn page_size(mut self, page_size: usize) -> InlineResults<F> {
+        self.page_size = page_size.clamp(1, MAX_INLINE_QUERY_RESULTS);
+        self
+    }
+
+    /// Builds the answer for the page requested by `query`, malformed offsets are treated as the first page.
+    /// If the results of the requested page are no longer cached, the answer is empty and ends paging.
+    pub fn answer(&self, query: &InlineQuery) -> AnswerInlineQuery {
+        let (generation, results, start) =
+            match parse_offset(&query.offset) {
+                Some((generation, start)) =>
+                    match self.page_results(&query.query, generation) {
+                        Some(results) =>
+                            (generation, results, start),
+                        None =>
+                            return AnswerInlineQuery::new(query.id.clone(), Vec::new())
+                    },
+                None => {
+                    let (generation, results) = self.results(&query.query);
+                    (generation, results, 0)
+                }
+            };
+        let start = min(start, results.len());
+        let end = min(start + self.page_size, results.len());
+        AnswerInlineQuery {
+            next_offset: if end < results.len() { Some(format!("{}:{}", generation, end)) } else { None },
+            ..AnswerInlineQuery::new(query.id.clone(), results[start..end].to_vec())
+        }
+    }
+
+    pub fn handle(&self, client: &BotApiClient, query: &InlineQuery, timeout: Duration) -> impl Future<Item=bool, Error=Error> {
+        client.answer_inline_query(&self.answer(query), timeout)
+    }
+
+    /// Telegram sends `ChosenInlineResult` only if inline feedback is enabled for the bot in @BotFather.
+    pub fn record_chosen(&self, result: &ChosenInlineResult) {
+        *self.chosen.lock().expect("Inline results mutex is poisoned").entry(result.result_id.clone()).or_insert(0) += 1;
+    }
+
+    pub fn chosen_count(&self, result_id: &str) -> usize {
+        self.chosen.lock().expect("Inline results mutex is poisoned").get(result_id).cloned().unwrap_or(0)
+    }
+
+    /// Number of times each result was chosen since the helper was created.
+    pub fn chosen_counts(&self) -> HashMap<String, usize> {
+        self.chosen.lock().expect("Inline results mutex is poisoned").clone()
+    }
+
+    // `produce` may be slow, so it runs unlocked and doesn't block answering other queries.
+    fn results(&self, query: &str) -> (usize, Arc<Vec<InlineQueryResult>>) {
+        if let Some(cached) = self.cached(query, Instant::now()) {
+            return cached;
+        }
+        let results = Arc::new((self.produce)(query));
+        let generation = self.generation.fetch_add(1, Ordering::Relaxed);
+        let now = Instant::now();
+        let mut entries = self.entries.lock().expect("Inline results mutex is poisoned");
+        entries.retain(|_, entry| entry.expires_at > now);
+        entries.insert(String::from(query), Entry { generation, results: results.clone(), expires_at: now + self.ttl });
+        (generation, results)
+    }
+
+    fn cached(&self, query: &str, now: Instant) -> Option<(usize, Arc<Vec<InlineQueryResult>>)> {
+        let entries = self.entries.lock().expect("Inline results mutex is poisoned");
+        entries.get(query)
+            .filter(|entry| entry.expires_at > now)
+            .map(|entry| (entry.generation, entry.results.clone()))
+    }
+
+    fn page_results(&self, query: &str, generation: usize) -> Option<Arc<Vec<InlineQueryResult>>> {
+        let mut entries = self.entries.lock().expect("Inline results mutex is poisoned");
+        let entry = entries.get_mut(query).filter(|entry| entry.generation == generation)?;
+        entry.expires_at = Instant::now() + self.ttl;
+        Some(entry.results.clone())
+    }
+}
+
+fn parse_offset(offset: &str) -> Option<(usize, usize)> {
+    let (generation, start) = offset.split_once(':')?;
+    Some((generation.parse().ok()?, start.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use requests::InlineQueryResultGame;
+    use serde_json;
+
+    fn query(offset: &str) -> InlineQuery {
+        serde_json::from_value(serde_json::json!({
+            "id": "query",
+            "from": { "id": 1, "is_bot": false, "first_name": "user" },
+            "query": "games",
+            "offset": offset,
+        })).expect("Query is valid")
+    }
+
+    fn ids(answer: &AnswerInlineQuery) -> Vec<&str> {
+        answer.results.iter()
+            .map(|result| match result {
+                InlineQueryResult::Game(game) => game.id.as_str(),
+                result => panic!("Unexpected result: {:?}", result),
+            })
+            .collect()
+    }
+
+    fn games(calls: &Cell<usize>) -> Vec<InlineQueryResult> {
+        calls.set(calls.get() + 1);
+        (0..3)
+            .map(|index| InlineQueryResult::Game(InlineQueryResultGame::new(format!("{}{}", calls.get(), index), String::from("game"))))
+            .collect()
+    }
+
+    #[test]
+    fn serves_pages_from_results_of_first_page() {
+        let calls = Cell::new(0);
+        let inline_results = InlineResults::new(Duration::from_secs(0), |_: &str| games(&calls)).page_size(2);
+
+        let first = inline_results.answer(&query(""));
+        let second = inline_results.answer(&query(first.next_offset.as_ref().expect("There are more results")));
+
+        assert_eq!(ids(&first), vec!["10", "11"]);
+        assert_eq!(ids(&second), vec!["12"]);
+        assert_eq!(second.next_offset, None);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn ends_paging_when_results_are_replaced() {
+        let calls = Cell::new(0);
+        let inline_results = InlineResults::new(Duration::from_secs(0), |_: &str| games(&calls)).page_size(2);
+
+        let first = inline_results.answer(&query(""));
+        inline_results.answer(&query(""));
+        let second = inline_results.answer(&query(first.next_offset.as_ref().expect("There are more results")));
+
+        assert_eq!((second.results.len(), second.next_offset), (0, None));
+        assert_eq!(calls.get(), 2);
+    }
+}
@@ -7,14 +7,22 @@ extern crate serde_derive;
 extern crate chrono;
 extern crate futures;
 extern crate tokio;
+#[cfg(test)]
+extern crate proptest;
 
 pub use self::api::{HttpClient, BotApiClient};
+pub use self::stream::AlbumStream;
 
 pub mod requests;
 pub mod error;
 pub mod responses;
+pub mod callback_data;
+pub mod pagination;
+pub mod formatting;
+pub mod inline_results;
 
 mod api;
 
 pub(crate) mod try_from;
-pub(crate) mod stream;
\ No newline at end of file
+pub(crate) mod stream;
+pub(crate) mod multipart;
\ No newline at end of file
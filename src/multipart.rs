@@ -0,0 +1,57 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+use requests::InputFile;
+
+pub struct Multipart {
+    pub boundary: String,
+    pub body: Vec<u8>,
+}
+
+// Top level fields of serialized request become text parts, nested objects and arrays are sent as json.
+// Files are referenced from the fields as `attach://<name>`, so each file becomes a part with its name.
+pub fn encode(request: Value, files: &[&InputFile]) -> Multipart {
+    let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let boundary = format!("relegram-boundary-{:x}{:x}", duration.as_secs(), duration.subsec_nanos());
+    let mut body = Vec::new();
+    if let Value::Object(fields) = request {
+        for (name, value) in fields {
+            let value =
+                match value {
+                    Value::Null => continue,
+                    Value::String(value) => value,
+                    value => value.to_string(),
+                };
+            body.extend_from_slice(format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n", boundary, name).as_bytes());
+            body.extend_from_slice(value.as_bytes());
+            body.extend_from_slice(b"\r\n");
+        }
+    }
+    for file in files {
+        let name = file.part_name();
+        body.extend_from_slice(format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n", boundary, name, name).as_bytes());
+        body.extend_from_slice(&file.data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+    Multipart { boundary, body }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn references_file_part_by_its_sanitized_name() {
+        let file = InputFile { name: String::from("a\"b\r\n.txt"), data: b"data".to_vec() };
+        let request = serde_json::json!({ "document": serde_json::to_value(&file).expect("File is serialized") });
+
+        let multipart = encode(request, &[&file]);
+
+        let body = String::from_utf8(multipart.body).expect("Body is utf-8");
+        assert!(body.contains("name=\"document\"\r\n\r\nattach://a%22b.txt\r\n"));
+        assert!(body.contains("name=\"a%22b.txt\"; filename=\"a%22b.txt\"\r\n"));
+    }
+}
@@ -0,0 +1,135 @@
+use std::cmp::{max, min};
+use std::time::Duration;
+
+use futures::Future;
+
+use api::BotApiClient;
+use callback_data;
+use error::{Error, CallbackDataError};
+use requests::*;
+use responses::CallbackQuery;
+
+// Callback data of navigation buttons is encoded as `["name",page]`, so a paginator can recognize its own queries after restarts.
+#[derive(Serialize, Deserialize)]
+struct PageData(String, usize);
+
+/// Renders items page by page into an inline keyboard: one button per item followed by prev/next buttons.
+#[derive(Debug, Clone)]
+pub struct Paginator {
+    name: String,
+    page_size: usize,
+    previous_text: String,
+    next_text: String,
+}
+
+impl Paginator {
+    /// Page size is clamped to 1..=98, so that items and navigation buttons fit into 100 buttons allowed by telegram.
+    pub fn new(name: String, page_size: usize) -> Result<Paginator, CallbackDataError> {
+        callback_data::encode(&PageData(name.clone(), usize::MAX))?;
+        Ok(Paginator {
+            name,
+            page_size: page_size.clamp(1, MAX_BUTTONS - 2),
+            previous_text: String::from("«"),
+            next_text: String::from("»"),
+        })
+    }
+
+    pub fn previous_text(mut self, text: String) -> Paginator {
+        self.previous_text = text;
+        self
+    }
+
+    pub fn next_text(mut self, text: String) -> Paginator {
+        self.next_text = text;
+        self
+    }
+
+    pub fn page_count(&self, items_count: usize) -> usize {
+        max(items_count.div_ceil(self.page_size), 1)
+    }
+
+    /// Pages past the end are empty.
+    pub fn page<'a, T>(&self, items: &'a [T], page: usize) -> &'a [T] {
+        let start = min(page.saturating_mul(self.page_size), items.len());
+        let end = min(start + self.page_size, items.len());
+        &items[start..end]
+    }
+
+    pub fn keyboard<T, F>(&self, items: &[T], page: usize, button: F) -> InlineKeyboard
+        where F: Fn(&T) -> InlineKeyboardButton {
+        let page_count = self.page_count(items.len());
+        let page = min(page, page_count - 1);
+        let mut navigation = Vec::new();
+        if page > 0 {
+            navigation.push(self.navigation_button(self.previous_text.clone(), page - 1));
+        }
+        if page + 1 < page_count {
+            navigation.push(self.navigation_button(self.next_text.clone(), page + 1));
+        }
+        let builder =
+            InlineKeyboardBuilder::new()
+                .columns(1)
+                .buttons(self.page(items, page).iter().map(button));
+        let builder = if navigation.is_empty() { builder } else { builder.row(navigation) };
+        builder
+            .build()
+            .expect("Page size is limited, so keyboard always fits telegram limits")
+    }
+
+    /// Returns requested page if the query was produced by this paginator.
+    pub fn parse(&self, query: &CallbackQuery) -> Option<usize> {
+        match query.decode_data() {
+            Ok(PageData(ref name, page)) if *name == self.name =>
+                Some(page),
+            _ =>
+                None
+        }
+    }
+
+    /// Edits the message that carried the keyboard to the requested page and answers the query.
+    /// Returns `None` if the query does not belong to this paginator.
+    pub fn handle<T, F, G>(&self,
+                           client: &BotApiClient,
+                           query: &CallbackQuery,
+                           items: &[T],
+                           text: G,
+                           button: F,
+                           timeout: Duration) -> Option<impl Future<Item=(), Error=Error>>
+        where F: Fn(&T) -> InlineKeyboardButton,
+              G: FnOnce(&[T], usize, usize) -> String {
+        let page = self.parse(query)?;
+        let target = query.message_target()?;
+        let page_count = self.page_count(items.len());
+        let page = min(page, page_count - 1);
+        let edit = EditMessageTextRequest {
+            reply_markup: Some(self.keyboard(items, page, button)),
+            ..EditMessageTextRequest::new(target, text(self.page(items, page), page, page_count))
+        };
+        let answer = AnswerCallbackQuery::new(query.id.clone());
+        let cloned_client = client.clone();
+        Some(client.edit_message_text(&edit, timeout)
+            .then(move |edited| {
+                cloned_client.answer_callback_query(&answer, timeout)
+                    .then(move |answered| edited.and(answered).map(|_| ()))
+            }))
+    }
+
+    fn navigation_button(&self, text: String, page: usize) -> InlineKeyboardButton {
+        InlineKeyboardButton::encoded_callback(text, &PageData(self.name.clone(), page))
+            .expect("Paginator name length is checked in Paginator::new")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_empty_page_past_the_end() {
+        let paginator = Paginator::new(String::from("items"), 2).expect("Name is short");
+        let items = [1, 2, 3];
+
+        assert_eq!(paginator.page(&items, 1), &[3]);
+        assert!(paginator.page(&items, usize::MAX / 2).is_empty());
+    }
+}
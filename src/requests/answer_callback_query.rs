@@ -18,4 +18,16 @@ impl Request for AnswerCallbackQuery {
     fn method(&self) -> &'static str {
         "answerCallbackQuery"
     }
+}
+
+impl AnswerCallbackQuery {
+    pub fn new(callback_query_id: String) -> AnswerCallbackQuery {
+        AnswerCallbackQuery {
+            callback_query_id,
+            text: None,
+            show_alert: false,
+            url: None,
+            cache_time: None,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+use requests::Request;
+use requests::inline_query_result::InlineQueryResult;
+use std::ops::Not;
+
+pub const MAX_INLINE_QUERY_RESULTS: usize = 50;
+
+/// At most `MAX_INLINE_QUERY_RESULTS` results are allowed per answer, pass `next_offset` to let telegram request more.
+#[derive(Serialize, Debug, Clone)]
+pub struct AnswerInlineQuery {
+    pub inline_query_id: String,
+    pub results: Vec<InlineQueryResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i32>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub is_personal: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub button: Option<InlineQueryResultsButton>,
+}
+
+/// Button shown above the results, opens either a private chat with the bot or a web app.
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultsButton {
+    pub text: String,
+    #[serde(flatten)]
+    pub kind: InlineQueryResultsButtonKind,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InlineQueryResultsButtonKind {
+    StartParameter { start_parameter: String },
+    WebApp { web_app: WebAppInfo },
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct WebAppInfo {
+    pub url: String,
+}
+
+impl AnswerInlineQuery {
+    pub fn new(inline_query_id: String, results: Vec<InlineQueryResult>) -> AnswerInlineQuery {
+        AnswerInlineQuery {
+            inline_query_id,
+            results,
+            cache_time: None,
+            is_personal: false,
+            next_offset: None,
+            button: None,
+        }
+    }
+}
+
+impl InlineQueryResultsButton {
+    /// `start_parameter` is passed to the bot in `/start` message, only `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed.
+    pub fn start(text: String, start_parameter: String) -> InlineQueryResultsButton {
+        InlineQueryResultsButton {
+            text,
+            kind: InlineQueryResultsButtonKind::StartParameter { start_parameter },
+        }
+    }
+
+    pub fn web_app(text: String, url: String) -> InlineQueryResultsButton {
+        InlineQueryResultsButton {
+            text,
+            kind: InlineQueryResultsButtonKind::WebApp { web_app: WebAppInfo { url } },
+        }
+    }
+}
+
+impl Request for AnswerInlineQuery {
+    fn method(&self) -> &'static str {
+        "answerInlineQuery"
+    }
+}
@@ -1,19 +1,44 @@
+use std::time::Duration;
+
 use requests::chat_id::ChatId;
 use requests::Request;
 
+/// Telegram shows an action for 5 seconds, so it is repeated a bit more often while an operation runs.
+pub const CHAT_ACTION_INTERVAL: Duration = Duration::from_secs(4);
+
 #[derive(Serialize, Debug, Clone)]
 pub struct SendChatAction {
     pub chat_id: ChatId,
     pub action: ChatAction,
 }
 
+impl SendChatAction {
+    pub fn new(chat_id: ChatId, action: ChatAction) -> SendChatAction {
+        SendChatAction {
+            chat_id,
+            action,
+        }
+    }
+}
+
 impl Request for SendChatAction {
     fn method(&self) -> &'static str {
         "sendChatAction"
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum ChatAction {
-    Typing
-}
\ No newline at end of file
+    Typing,
+    UploadPhoto,
+    RecordVideo,
+    UploadVideo,
+    RecordVoice,
+    UploadVoice,
+    UploadDocument,
+    ChooseSticker,
+    FindLocation,
+    RecordVideoNote,
+    UploadVideoNote,
+}
@@ -0,0 +1,212 @@
+use std::ops::Not;
+
+use chrono::{DateTime, Utc};
+use serde::Serializer;
+
+use requests::Request;
+use requests::chat_id::ChatId;
+use responses::{ChatAdministratorRights, ChatPermissions};
+
+/// `until_date` closer than 30 seconds or further than 366 days from now means forever.
+#[derive(Serialize, Debug, Clone)]
+pub struct BanChatMemberRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_date")]
+    pub until_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub revoke_messages: bool,
+}
+
+/// Without `only_if_banned` unbanning a current member removes them from the chat, which is what kicking relies on.
+#[derive(Serialize, Debug, Clone)]
+pub struct UnbanChatMemberRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub only_if_banned: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RestrictChatMemberRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+    pub permissions: ChatPermissions,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub use_independent_chat_permissions: bool,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_date")]
+    pub until_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SetChatPermissionsRequest {
+    pub chat_id: ChatId,
+    pub permissions: ChatPermissions,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub use_independent_chat_permissions: bool,
+}
+
+/// Pass default rights to demote an administrator.
+#[derive(Serialize, Debug, Clone)]
+pub struct PromoteChatMemberRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+    #[serde(flatten)]
+    pub rights: ChatAdministratorRights,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SetChatAdministratorCustomTitleRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+    pub custom_title: String,
+}
+
+/// Rights suggested to users adding the bot as an administrator, `None` resets them.
+#[derive(Serialize, Debug, Clone)]
+pub struct SetMyDefaultAdministratorRightsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rights: Option<ChatAdministratorRights>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub for_channels: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GetMyDefaultAdministratorRightsRequest {
+    #[serde(skip_serializing_if = "Not::not")]
+    pub for_channels: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ApproveChatJoinRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DeclineChatJoinRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+}
+
+impl BanChatMemberRequest {
+    pub fn new(chat_id: ChatId, user_id: i64) -> BanChatMemberRequest {
+        BanChatMemberRequest {
+            chat_id,
+            user_id,
+            until_date: None,
+            revoke_messages: false,
+        }
+    }
+}
+
+impl UnbanChatMemberRequest {
+    pub fn new(chat_id: ChatId, user_id: i64) -> UnbanChatMemberRequest {
+        UnbanChatMemberRequest {
+            chat_id,
+            user_id,
+            only_if_banned: false,
+        }
+    }
+}
+
+impl RestrictChatMemberRequest {
+    pub fn new(chat_id: ChatId, user_id: i64, permissions: ChatPermissions) -> RestrictChatMemberRequest {
+        RestrictChatMemberRequest {
+            chat_id,
+            user_id,
+            permissions,
+            use_independent_chat_permissions: false,
+            until_date: None,
+        }
+    }
+}
+
+impl SetChatPermissionsRequest {
+    pub fn new(chat_id: ChatId, permissions: ChatPermissions) -> SetChatPermissionsRequest {
+        SetChatPermissionsRequest {
+            chat_id,
+            permissions,
+            use_independent_chat_permissions: false,
+        }
+    }
+}
+
+impl PromoteChatMemberRequest {
+    pub fn new(chat_id: ChatId, user_id: i64, rights: ChatAdministratorRights) -> PromoteChatMemberRequest {
+        PromoteChatMemberRequest {
+            chat_id,
+            user_id,
+            rights,
+        }
+    }
+}
+
+impl Request for BanChatMemberRequest {
+    fn method(&self) -> &'static str {
+        "banChatMember"
+    }
+}
+
+impl Request for UnbanChatMemberRequest {
+    fn method(&self) -> &'static str {
+        "unbanChatMember"
+    }
+}
+
+impl Request for RestrictChatMemberRequest {
+    fn method(&self) -> &'static str {
+        "restrictChatMember"
+    }
+}
+
+impl Request for SetChatPermissionsRequest {
+    fn method(&self) -> &'static str {
+        "setChatPermissions"
+    }
+}
+
+impl Request for PromoteChatMemberRequest {
+    fn method(&self) -> &'static str {
+        "promoteChatMember"
+    }
+}
+
+impl Request for SetChatAdministratorCustomTitleRequest {
+    fn method(&self) -> &'static str {
+        "setChatAdministratorCustomTitle"
+    }
+}
+
+impl Request for SetMyDefaultAdministratorRightsRequest {
+    fn method(&self) -> &'static str {
+        "setMyDefaultAdministratorRights"
+    }
+}
+
+impl Request for GetMyDefaultAdministratorRightsRequest {
+    fn method(&self) -> &'static str {
+        "getMyDefaultAdministratorRights"
+    }
+}
+
+impl Request for ApproveChatJoinRequest {
+    fn method(&self) -> &'static str {
+        "approveChatJoinRequest"
+    }
+}
+
+impl Request for DeclineChatJoinRequest {
+    fn method(&self) -> &'static str {
+        "declineChatJoinRequest"
+    }
+}
+
+pub(crate) fn serialize_date<S: Serializer>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error> {
+    match date {
+        Some(date) =>
+            serializer.serialize_i64(date.timestamp()),
+        None =>
+            serializer.serialize_none()
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::Not;
+
+use requests::Request;
+use requests::chat_id::ChatId;
+use requests::send_message::InputFile;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SetChatTitleRequest {
+    pub chat_id: ChatId,
+    pub title: String,
+}
+
+/// `None` removes the description.
+#[derive(Serialize, Debug, Clone)]
+pub struct SetChatDescriptionRequest {
+    pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Chat photo can only be uploaded, file ids and urls are not accepted.
+#[derive(Serialize, Debug, Clone)]
+pub struct SetChatPhotoRequest {
+    pub chat_id: ChatId,
+    pub photo: InputFile,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DeleteChatPhotoRequest {
+    pub chat_id: ChatId,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PinChatMessageRequest {
+    pub chat_id: ChatId,
+    pub message_id: i64,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+}
+
+/// `None` unpins the most recent pinned message.
+#[derive(Serialize, Debug, Clone)]
+pub struct UnpinChatMessageRequest {
+    pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UnpinAllChatMessagesRequest {
+    pub chat_id: ChatId,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LeaveChatRequest {
+    pub chat_id: ChatId,
+}
+
+/// Only available for supergroups where `ChatFullInfo::can_set_sticker_set` is set.
+#[derive(Serialize, Debug, Clone)]
+pub struct SetChatStickerSetRequest {
+    pub chat_id: ChatId,
+    pub sticker_set_name: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DeleteChatStickerSetRequest {
+    pub chat_id: ChatId,
+}
+
+impl PinChatMessageRequest {
+    pub fn new(chat_id: ChatId, message_id: i64) -> PinChatMessageRequest {
+        PinChatMessageRequest {
+            chat_id,
+            message_id,
+            disable_notification: false,
+        }
+    }
+}
+
+impl Request for SetChatTitleRequest {
+    fn method(&self) -> &'static str {
+        "setChatTitle"
+    }
+}
+
+impl Request for SetChatDescriptionRequest {
+    fn method(&self) -> &'static str {
+        "setChatDescription"
+    }
+}
+
+impl Request for SetChatPhotoRequest {
+    fn method(&self) -> &'static str {
+        "setChatPhoto"
+    }
+
+    fn files(&self) -> Vec<&InputFile> {
+        vec![&self.photo]
+    }
+}
+
+impl Request for DeleteChatPhotoRequest {
+    fn method(&self) -> &'static str {
+        "deleteChatPhoto"
+    }
+}
+
+impl Request for PinChatMessageRequest {
+    fn method(&self) -> &'static str {
+        "pinChatMessage"
+    }
+}
+
+impl Request for UnpinChatMessageRequest {
+    fn method(&self) -> &'static str {
+        "unpinChatMessage"
+    }
+}
+
+impl Request for UnpinAllChatMessagesRequest {
+    fn method(&self) -> &'static str {
+        "unpinAllChatMessages"
+    }
+}
+
+impl Request for LeaveChatRequest {
+    fn method(&self) -> &'static str {
+        "leaveChat"
+    }
+}
+
+impl Request for SetChatStickerSetRequest {
+    fn method(&self) -> &'static str {
+        "setChatStickerSet"
+    }
+}
+
+impl Request for DeleteChatStickerSetRequest {
+    fn method(&self) -> &'static str {
+        "deleteChatStickerSet"
+    }
+}
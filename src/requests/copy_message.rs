@@ -0,0 +1,84 @@
+use std::ops::Not;
+use requests::Request;
+use requests::chat_id::ChatId;
+use requests::reply_markup::ReplyMarkup;
+use requests::send_message::ParseMode;
+use requests::text_entity::TextEntity;
+
+/// Copies a message without a link to the original. `caption` replaces the caption of media messages, the original one is kept if `None`.
+#[derive(Serialize, Debug, Clone)]
+pub struct CopyMessageRequest {
+    pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub from_chat_id: ChatId,
+    pub message_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+/// Message ids must be in increasing order, albums keep their grouping.
+#[derive(Serialize, Debug, Clone)]
+pub struct CopyMessagesRequest {
+    pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub from_chat_id: ChatId,
+    pub message_ids: Vec<i64>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub remove_caption: bool,
+}
+
+impl CopyMessageRequest {
+    pub fn new(chat_id: ChatId, from_chat_id: ChatId, message_id: i64) -> CopyMessageRequest {
+        CopyMessageRequest {
+            chat_id,
+            message_thread_id: None,
+            from_chat_id,
+            message_id,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+impl CopyMessagesRequest {
+    pub fn new(chat_id: ChatId, from_chat_id: ChatId, message_ids: Vec<i64>) -> CopyMessagesRequest {
+        CopyMessagesRequest {
+            chat_id,
+            message_thread_id: None,
+            from_chat_id,
+            message_ids,
+            disable_notification: false,
+            remove_caption: false,
+        }
+    }
+}
+
+impl Request for CopyMessageRequest {
+    fn method(&self) -> &'static str {
+        "copyMessage"
+    }
+}
+
+impl Request for CopyMessagesRequest {
+    fn method(&self) -> &'static str {
+        "copyMessages"
+    }
+}
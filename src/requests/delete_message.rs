@@ -0,0 +1,54 @@
+use requests::Request;
+use requests::chat_id::ChatId;
+
+pub const MAX_DELETE_MESSAGES: usize = 100;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DeleteMessageRequest {
+    pub chat_id: ChatId,
+    pub message_id: i64,
+}
+
+/// Deletes up to `MAX_DELETE_MESSAGES` messages at once, missing messages are skipped by telegram.
+#[derive(Serialize, Debug, Clone)]
+pub struct DeleteMessagesRequest {
+    pub chat_id: ChatId,
+    pub message_ids: Vec<i64>,
+}
+
+impl DeleteMessageRequest {
+    pub fn new(chat_id: ChatId, message_id: i64) -> DeleteMessageRequest {
+        DeleteMessageRequest {
+            chat_id,
+            message_id,
+        }
+    }
+}
+
+impl DeleteMessagesRequest {
+    pub fn new(chat_id: ChatId, message_ids: Vec<i64>) -> DeleteMessagesRequest {
+        DeleteMessagesRequest {
+            chat_id,
+            message_ids,
+        }
+    }
+
+    /// Splits `message_ids` into requests of at most `MAX_DELETE_MESSAGES` ids each.
+    pub fn chunks(chat_id: ChatId, message_ids: &[i64]) -> Vec<DeleteMessagesRequest> {
+        message_ids.chunks(MAX_DELETE_MESSAGES)
+            .map(|chunk| DeleteMessagesRequest::new(chat_id.clone(), chunk.to_vec()))
+            .collect()
+    }
+}
+
+impl Request for DeleteMessageRequest {
+    fn method(&self) -> &'static str {
+        "deleteMessage"
+    }
+}
+
+impl Request for DeleteMessagesRequest {
+    fn method(&self) -> &'static str {
+        "deleteMessages"
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::Not;
+use requests::Request;
+use requests::chat_id::ChatId;
+use requests::send_message::ParseMode;
+use requests::reply_markup::InlineKeyboard;
+use requests::text_entity::TextEntity;
+use requests::input_media::InputMedia;
+use requests::send_message::InputFile;
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum MessageTarget {
+    Chat { chat_id: ChatId, message_id: i64 },
+    Inline { inline_message_id: String },
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EditMessageTextRequest {
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_web_page_preview: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+}
+
+impl EditMessageTextRequest {
+    pub fn new(target: MessageTarget, text: String) -> EditMessageTextRequest {
+        EditMessageTextRequest {
+            target,
+            text,
+            parse_mode: None,
+            entities: None,
+            disable_web_page_preview: false,
+            reply_markup: None,
+        }
+    }
+}
+
+impl Request for EditMessageTextRequest {
+    fn method(&self) -> &'static str {
+        "editMessageText"
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EditMessageCaptionRequest {
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+}
+
+impl EditMessageCaptionRequest {
+    /// Pass `None` to remove the caption.
+    pub fn new(target: MessageTarget, caption: Option<String>) -> EditMessageCaptionRequest {
+        EditMessageCaptionRequest {
+            target,
+            caption,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+        }
+    }
+}
+
+impl Request for EditMessageCaptionRequest {
+    fn method(&self) -> &'static str {
+        "editMessageCaption"
+    }
+}
+
+/// Inline messages can't be edited with a newly uploaded file, use a file id or url for them.
+#[derive(Serialize, Debug, Clone)]
+pub struct EditMessageMediaRequest {
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    pub media: InputMedia,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+}
+
+impl EditMessageMediaRequest {
+    pub fn new(target: MessageTarget, media: InputMedia) -> EditMessageMediaRequest {
+        EditMessageMediaRequest {
+            target,
+            media,
+            reply_markup: None,
+        }
+    }
+}
+
+impl Request for EditMessageMediaRequest {
+    fn method(&self) -> &'static str {
+        "editMessageMedia"
+    }
+
+    fn files(&self) -> Vec<&InputFile> {
+        self.media.files()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EditMessageReplyMarkupRequest {
+    #[serde(flatten)]
+    pub target: MessageTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+}
+
+impl EditMessageReplyMarkupRequest {
+    /// Pass `None` to remove the keyboard.
+    pub fn new(target: MessageTarget, reply_markup: Option<InlineKeyboard>) -> EditMessageReplyMarkupRequest {
+        EditMessageReplyMarkupRequest {
+            target,
+            reply_markup,
+        }
+    }
+}
+
+impl Request for EditMessageReplyMarkupRequest {
+    fn method(&self) -> &'static str {
+        "editMessageReplyMarkup"
+    }
+}
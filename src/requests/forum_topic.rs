@@ -0,0 +1,152 @@
+use requests::Request;
+use requests::chat_id::ChatId;
+
+/// `icon_color` must be one of the colors telegram allows, e.g. `0x6FB9F0`.
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateForumTopicRequest {
+    pub chat_id: ChatId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Omitted fields are kept, an empty `icon_custom_emoji_id` removes the icon.
+#[derive(Serialize, Debug, Clone)]
+pub struct EditForumTopicRequest {
+    pub chat_id: ChatId,
+    pub message_thread_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CloseForumTopicRequest {
+    pub chat_id: ChatId,
+    pub message_thread_id: i64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ReopenForumTopicRequest {
+    pub chat_id: ChatId,
+    pub message_thread_id: i64,
+}
+
+/// Deletes the topic along with all its messages.
+#[derive(Serialize, Debug, Clone)]
+pub struct DeleteForumTopicRequest {
+    pub chat_id: ChatId,
+    pub message_thread_id: i64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EditGeneralForumTopicRequest {
+    pub chat_id: ChatId,
+    pub name: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CloseGeneralForumTopicRequest {
+    pub chat_id: ChatId,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ReopenGeneralForumTopicRequest {
+    pub chat_id: ChatId,
+}
+
+/// The topic is closed automatically if it was open.
+#[derive(Serialize, Debug, Clone)]
+pub struct HideGeneralForumTopicRequest {
+    pub chat_id: ChatId,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UnhideGeneralForumTopicRequest {
+    pub chat_id: ChatId,
+}
+
+impl CreateForumTopicRequest {
+    pub fn new(chat_id: ChatId, name: String) -> CreateForumTopicRequest {
+        CreateForumTopicRequest {
+            chat_id,
+            name,
+            icon_color: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+}
+
+impl EditForumTopicRequest {
+    pub fn new(chat_id: ChatId, message_thread_id: i64) -> EditForumTopicRequest {
+        EditForumTopicRequest {
+            chat_id,
+            message_thread_id,
+            name: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+}
+
+impl Request for CreateForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "createForumTopic"
+    }
+}
+
+impl Request for EditForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "editForumTopic"
+    }
+}
+
+impl Request for CloseForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "closeForumTopic"
+    }
+}
+
+impl Request for ReopenForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "reopenForumTopic"
+    }
+}
+
+impl Request for DeleteForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "deleteForumTopic"
+    }
+}
+
+impl Request for EditGeneralForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "editGeneralForumTopic"
+    }
+}
+
+impl Request for CloseGeneralForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "closeGeneralForumTopic"
+    }
+}
+
+impl Request for ReopenGeneralForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "reopenGeneralForumTopic"
+    }
+}
+
+impl Request for HideGeneralForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "hideGeneralForumTopic"
+    }
+}
+
+impl Request for UnhideGeneralForumTopicRequest {
+    fn method(&self) -> &'static str {
+        "unhideGeneralForumTopic"
+    }
+}
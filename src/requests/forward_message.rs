@@ -6,6 +6,9 @@ use requests::Request;
 pub struct ForwardMessageRequest {
     pub chat_id: ChatId,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+
     pub from_chat_id: ChatId,
 
     #[serde(skip_serializing_if = "Not::not")]
@@ -14,9 +17,54 @@ pub struct ForwardMessageRequest {
     pub message_id: i64
 }
 
+/// Message ids must be in increasing order, albums keep their grouping.
+#[derive(Serialize, Debug, Clone)]
+pub struct ForwardMessagesRequest {
+    pub chat_id: ChatId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+
+    pub from_chat_id: ChatId,
+
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+
+    pub message_ids: Vec<i64>
+}
+
+impl ForwardMessageRequest {
+    pub fn new(chat_id: ChatId, from_chat_id: ChatId, message_id: i64) -> ForwardMessageRequest {
+        ForwardMessageRequest {
+            chat_id,
+            message_thread_id: None,
+            from_chat_id,
+            disable_notification: false,
+            message_id,
+        }
+    }
+}
+
+impl ForwardMessagesRequest {
+    pub fn new(chat_id: ChatId, from_chat_id: ChatId, message_ids: Vec<i64>) -> ForwardMessagesRequest {
+        ForwardMessagesRequest {
+            chat_id,
+            message_thread_id: None,
+            from_chat_id,
+            disable_notification: false,
+            message_ids,
+        }
+    }
+}
 
 impl Request for ForwardMessageRequest {
     fn method(&self) -> &'static str {
         "forwardMessage"
     }
-}
\ No newline at end of file
+}
+
+impl Request for ForwardMessagesRequest {
+    fn method(&self) -> &'static str {
+        "forwardMessages"
+    }
+}
@@ -0,0 +1,48 @@
+use requests::Request;
+use requests::chat_id::ChatId;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GetChatRequest {
+    pub chat_id: ChatId
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GetChatMemberRequest {
+    pub chat_id: ChatId,
+    pub user_id: i64,
+}
+
+/// Bots are not included in the result.
+#[derive(Serialize, Debug, Clone)]
+pub struct GetChatAdministratorsRequest {
+    pub chat_id: ChatId
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GetChatMemberCountRequest {
+    pub chat_id: ChatId
+}
+
+impl Request for GetChatRequest {
+    fn method(&self) -> &'static str {
+        "getChat"
+    }
+}
+
+impl Request for GetChatMemberRequest {
+    fn method(&self) -> &'static str {
+        "getChatMember"
+    }
+}
+
+impl Request for GetChatAdministratorsRequest {
+    fn method(&self) -> &'static str {
+        "getChatAdministrators"
+    }
+}
+
+impl Request for GetChatMemberCountRequest {
+    fn method(&self) -> &'static str {
+        "getChatMemberCount"
+    }
+}
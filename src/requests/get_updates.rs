@@ -27,6 +27,11 @@ pub enum AllowedUpdate {
     CallbackQuery,
     ShippingQuery,
     PreCheckoutQuery,
+    MyChatMember,
+    ChatMember,
+    ChatJoinRequest,
+    MessageReaction,
+    MessageReactionCount,
 }
 
 impl Request for GetUpdatesRequest {
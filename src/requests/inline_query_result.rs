@@ -0,0 +1,1007 @@
+use requests::reply_markup::InlineKeyboard;
+use requests::send_message::ParseMode;
+use requests::text_entity::TextEntity;
+use std::ops::Not;
+
+/// Results of the same kind share the telegram type, cached ones reference files already uploaded to telegram by file id.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum InlineQueryResult {
+    #[serde(rename = "article")]
+    Article(InlineQueryResultArticle),
+    #[serde(rename = "photo")]
+    Photo(InlineQueryResultPhoto),
+    #[serde(rename = "gif")]
+    Gif(InlineQueryResultGif),
+    #[serde(rename = "mpeg4_gif")]
+    Mpeg4Gif(InlineQueryResultMpeg4Gif),
+    #[serde(rename = "video")]
+    Video(InlineQueryResultVideo),
+    #[serde(rename = "audio")]
+    Audio(InlineQueryResultAudio),
+    #[serde(rename = "voice")]
+    Voice(InlineQueryResultVoice),
+    #[serde(rename = "document")]
+    Document(InlineQueryResultDocument),
+    #[serde(rename = "location")]
+    Location(InlineQueryResultLocation),
+    #[serde(rename = "venue")]
+    Venue(InlineQueryResultVenue),
+    #[serde(rename = "contact")]
+    Contact(InlineQueryResultContact),
+    #[serde(rename = "game")]
+    Game(InlineQueryResultGame),
+    #[serde(rename = "photo")]
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    #[serde(rename = "gif")]
+    CachedGif(InlineQueryResultCachedGif),
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    #[serde(rename = "sticker")]
+    CachedSticker(InlineQueryResultCachedSticker),
+    #[serde(rename = "document")]
+    CachedDocument(InlineQueryResultCachedDocument),
+    #[serde(rename = "video")]
+    CachedVideo(InlineQueryResultCachedVideo),
+    #[serde(rename = "voice")]
+    CachedVoice(InlineQueryResultCachedVoice),
+    #[serde(rename = "audio")]
+    CachedAudio(InlineQueryResultCachedAudio),
+}
+
+/// Content sent instead of the result itself.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InputMessageContent {
+    Text(InputTextMessageContent),
+    Location(InputLocationMessageContent),
+    Venue(InputVenueMessageContent),
+    Contact(InputContactMessageContent),
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InputTextMessageContent {
+    pub message_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_web_page_preview: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InputLocationMessageContent {
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InputVenueMessageContent {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InputContactMessageContent {
+    pub phone_number: String,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultArticle {
+    pub id: String,
+    pub title: String,
+    pub input_message_content: InputMessageContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+}
+
+/// Photo must be in jpeg format and no larger than 5 MB.
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultPhoto {
+    pub id: String,
+    pub photo_url: String,
+    pub thumbnail_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultGif {
+    pub id: String,
+    pub gif_url: String,
+    pub thumbnail_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+/// Video animation without sound.
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultMpeg4Gif {
+    pub id: String,
+    pub mpeg4_url: String,
+    pub thumbnail_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+/// `mime_type` is either "text/html" for a page with an embedded player or "video/mp4".
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultVideo {
+    pub id: String,
+    pub video_url: String,
+    pub mime_type: String,
+    pub thumbnail_url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultAudio {
+    pub id: String,
+    pub audio_url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultVoice {
+    pub id: String,
+    pub voice_url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+/// Only pdf and zip files can be sent by url, `mime_type` is "application/pdf" or "application/zip".
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultDocument {
+    pub id: String,
+    pub title: String,
+    pub document_url: String,
+    pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultLocation {
+    pub id: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultVenue {
+    pub id: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultContact {
+    pub id: String,
+    pub phone_number: String,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultGame {
+    pub id: String,
+    pub game_short_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedPhoto {
+    pub id: String,
+    pub photo_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedGif {
+    pub id: String,
+    pub gif_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedMpeg4Gif {
+    pub id: String,
+    pub mpeg4_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedSticker {
+    pub id: String,
+    pub sticker_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedDocument {
+    pub id: String,
+    pub title: String,
+    pub document_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedVideo {
+    pub id: String,
+    pub video_file_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedVoice {
+    pub id: String,
+    pub voice_file_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InlineQueryResultCachedAudio {
+    pub id: String,
+    pub audio_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InputTextMessageContent {
+    pub fn new(message_text: String) -> InputTextMessageContent {
+        InputTextMessageContent {
+            message_text,
+            parse_mode: None,
+            entities: None,
+            disable_web_page_preview: false,
+        }
+    }
+}
+
+impl InlineQueryResultArticle {
+    pub fn new(id: String, title: String, input_message_content: InputMessageContent) -> InlineQueryResultArticle {
+        InlineQueryResultArticle {
+            id,
+            title,
+            input_message_content,
+            reply_markup: None,
+            url: None,
+            description: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+impl InlineQueryResultPhoto {
+    pub fn new(id: String, photo_url: String, thumbnail_url: String) -> InlineQueryResultPhoto {
+        InlineQueryResultPhoto {
+            id,
+            photo_url,
+            thumbnail_url,
+            photo_width: None,
+            photo_height: None,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultGif {
+    pub fn new(id: String, gif_url: String, thumbnail_url: String) -> InlineQueryResultGif {
+        InlineQueryResultGif {
+            id,
+            gif_url,
+            thumbnail_url,
+            gif_width: None,
+            gif_height: None,
+            gif_duration: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultMpeg4Gif {
+    pub fn new(id: String, mpeg4_url: String, thumbnail_url: String) -> InlineQueryResultMpeg4Gif {
+        InlineQueryResultMpeg4Gif {
+            id,
+            mpeg4_url,
+            thumbnail_url,
+            mpeg4_width: None,
+            mpeg4_height: None,
+            mpeg4_duration: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultVideo {
+    pub fn new(id: String, video_url: String, mime_type: String, thumbnail_url: String, title: String) -> InlineQueryResultVideo {
+        InlineQueryResultVideo {
+            id,
+            video_url,
+            mime_type,
+            thumbnail_url,
+            title,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            video_width: None,
+            video_height: None,
+            video_duration: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultAudio {
+    pub fn new(id: String, audio_url: String, title: String) -> InlineQueryResultAudio {
+        InlineQueryResultAudio {
+            id,
+            audio_url,
+            title,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            performer: None,
+            audio_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultVoice {
+    pub fn new(id: String, voice_url: String, title: String) -> InlineQueryResultVoice {
+        InlineQueryResultVoice {
+            id,
+            voice_url,
+            title,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            voice_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultDocument {
+    pub fn new(id: String, title: String, document_url: String, mime_type: String) -> InlineQueryResultDocument {
+        InlineQueryResultDocument {
+            id,
+            title,
+            document_url,
+            mime_type,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+impl InlineQueryResultLocation {
+    pub fn new(id: String, latitude: f64, longitude: f64, title: String) -> InlineQueryResultLocation {
+        InlineQueryResultLocation {
+            id,
+            latitude,
+            longitude,
+            title,
+            live_period: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+impl InlineQueryResultVenue {
+    pub fn new(id: String, latitude: f64, longitude: f64, title: String, address: String) -> InlineQueryResultVenue {
+        InlineQueryResultVenue {
+            id,
+            latitude,
+            longitude,
+            title,
+            address,
+            foursquare_id: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+impl InlineQueryResultContact {
+    pub fn new(id: String, phone_number: String, first_name: String) -> InlineQueryResultContact {
+        InlineQueryResultContact {
+            id,
+            phone_number,
+            first_name,
+            last_name: None,
+            vcard: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+impl InlineQueryResultGame {
+    pub fn new(id: String, game_short_name: String) -> InlineQueryResultGame {
+        InlineQueryResultGame {
+            id,
+            game_short_name,
+            reply_markup: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedPhoto {
+    pub fn new(id: String, photo_file_id: String) -> InlineQueryResultCachedPhoto {
+        InlineQueryResultCachedPhoto {
+            id,
+            photo_file_id,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedGif {
+    pub fn new(id: String, gif_file_id: String) -> InlineQueryResultCachedGif {
+        InlineQueryResultCachedGif {
+            id,
+            gif_file_id,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedMpeg4Gif {
+    pub fn new(id: String, mpeg4_file_id: String) -> InlineQueryResultCachedMpeg4Gif {
+        InlineQueryResultCachedMpeg4Gif {
+            id,
+            mpeg4_file_id,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedSticker {
+    pub fn new(id: String, sticker_file_id: String) -> InlineQueryResultCachedSticker {
+        InlineQueryResultCachedSticker {
+            id,
+            sticker_file_id,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedDocument {
+    pub fn new(id: String, title: String, document_file_id: String) -> InlineQueryResultCachedDocument {
+        InlineQueryResultCachedDocument {
+            id,
+            title,
+            document_file_id,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedVideo {
+    pub fn new(id: String, video_file_id: String, title: String) -> InlineQueryResultCachedVideo {
+        InlineQueryResultCachedVideo {
+            id,
+            video_file_id,
+            title,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedVoice {
+    pub fn new(id: String, voice_file_id: String, title: String) -> InlineQueryResultCachedVoice {
+        InlineQueryResultCachedVoice {
+            id,
+            voice_file_id,
+            title,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResultCachedAudio {
+    pub fn new(id: String, audio_file_id: String) -> InlineQueryResultCachedAudio {
+        InlineQueryResultCachedAudio {
+            id,
+            audio_file_id,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+impl InlineQueryResult {
+    pub fn id(&self) -> &str {
+        match self {
+            InlineQueryResult::Article(InlineQueryResultArticle { id, .. }) |
+            InlineQueryResult::Photo(InlineQueryResultPhoto { id, .. }) |
+            InlineQueryResult::Gif(InlineQueryResultGif { id, .. }) |
+            InlineQueryResult::Mpeg4Gif(InlineQueryResultMpeg4Gif { id, .. }) |
+            InlineQueryResult::Video(InlineQueryResultVideo { id, .. }) |
+            InlineQueryResult::Audio(InlineQueryResultAudio { id, .. }) |
+            InlineQueryResult::Voice(InlineQueryResultVoice { id, .. }) |
+            InlineQueryResult::Document(InlineQueryResultDocument { id, .. }) |
+            InlineQueryResult::Location(InlineQueryResultLocation { id, .. }) |
+            InlineQueryResult::Venue(InlineQueryResultVenue { id, .. }) |
+            InlineQueryResult::Contact(InlineQueryResultContact { id, .. }) |
+            InlineQueryResult::Game(InlineQueryResultGame { id, .. }) |
+            InlineQueryResult::CachedPhoto(InlineQueryResultCachedPhoto { id, .. }) |
+            InlineQueryResult::CachedGif(InlineQueryResultCachedGif { id, .. }) |
+            InlineQueryResult::CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif { id, .. }) |
+            InlineQueryResult::CachedSticker(InlineQueryResultCachedSticker { id, .. }) |
+            InlineQueryResult::CachedDocument(InlineQueryResultCachedDocument { id, .. }) |
+            InlineQueryResult::CachedVideo(InlineQueryResultCachedVideo { id, .. }) |
+            InlineQueryResult::CachedVoice(InlineQueryResultCachedVoice { id, .. }) |
+            InlineQueryResult::CachedAudio(InlineQueryResultCachedAudio { id, .. }) =>
+                id
+        }
+    }
+}
+
+impl From<InlineQueryResultArticle> for InlineQueryResult {
+    fn from(result: InlineQueryResultArticle) -> InlineQueryResult {
+        InlineQueryResult::Article(result)
+    }
+}
+
+impl From<InlineQueryResultPhoto> for InlineQueryResult {
+    fn from(result: InlineQueryResultPhoto) -> InlineQueryResult {
+        InlineQueryResult::Photo(result)
+    }
+}
+
+impl From<InlineQueryResultGif> for InlineQueryResult {
+    fn from(result: InlineQueryResultGif) -> InlineQueryResult {
+        InlineQueryResult::Gif(result)
+    }
+}
+
+impl From<InlineQueryResultMpeg4Gif> for InlineQueryResult {
+    fn from(result: InlineQueryResultMpeg4Gif) -> InlineQueryResult {
+        InlineQueryResult::Mpeg4Gif(result)
+    }
+}
+
+impl From<InlineQueryResultVideo> for InlineQueryResult {
+    fn from(result: InlineQueryResultVideo) -> InlineQueryResult {
+        InlineQueryResult::Video(result)
+    }
+}
+
+impl From<InlineQueryResultAudio> for InlineQueryResult {
+    fn from(result: InlineQueryResultAudio) -> InlineQueryResult {
+        InlineQueryResult::Audio(result)
+    }
+}
+
+impl From<InlineQueryResultVoice> for InlineQueryResult {
+    fn from(result: InlineQueryResultVoice) -> InlineQueryResult {
+        InlineQueryResult::Voice(result)
+    }
+}
+
+impl From<InlineQueryResultDocument> for InlineQueryResult {
+    fn from(result: InlineQueryResultDocument) -> InlineQueryResult {
+        InlineQueryResult::Document(result)
+    }
+}
+
+impl From<InlineQueryResultLocation> for InlineQueryResult {
+    fn from(result: InlineQueryResultLocation) -> InlineQueryResult {
+        InlineQueryResult::Location(result)
+    }
+}
+
+impl From<InlineQueryResultVenue> for InlineQueryResult {
+    fn from(result: InlineQueryResultVenue) -> InlineQueryResult {
+        InlineQueryResult::Venue(result)
+    }
+}
+
+impl From<InlineQueryResultContact> for InlineQueryResult {
+    fn from(result: InlineQueryResultContact) -> InlineQueryResult {
+        InlineQueryResult::Contact(result)
+    }
+}
+
+impl From<InlineQueryResultGame> for InlineQueryResult {
+    fn from(result: InlineQueryResultGame) -> InlineQueryResult {
+        InlineQueryResult::Game(result)
+    }
+}
+
+impl From<InlineQueryResultCachedPhoto> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedPhoto) -> InlineQueryResult {
+        InlineQueryResult::CachedPhoto(result)
+    }
+}
+
+impl From<InlineQueryResultCachedGif> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedGif) -> InlineQueryResult {
+        InlineQueryResult::CachedGif(result)
+    }
+}
+
+impl From<InlineQueryResultCachedMpeg4Gif> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedMpeg4Gif) -> InlineQueryResult {
+        InlineQueryResult::CachedMpeg4Gif(result)
+    }
+}
+
+impl From<InlineQueryResultCachedSticker> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedSticker) -> InlineQueryResult {
+        InlineQueryResult::CachedSticker(result)
+    }
+}
+
+impl From<InlineQueryResultCachedDocument> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedDocument) -> InlineQueryResult {
+        InlineQueryResult::CachedDocument(result)
+    }
+}
+
+impl From<InlineQueryResultCachedVideo> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedVideo) -> InlineQueryResult {
+        InlineQueryResult::CachedVideo(result)
+    }
+}
+
+impl From<InlineQueryResultCachedVoice> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedVoice) -> InlineQueryResult {
+        InlineQueryResult::CachedVoice(result)
+    }
+}
+
+impl From<InlineQueryResultCachedAudio> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedAudio) -> InlineQueryResult {
+        InlineQueryResult::CachedAudio(result)
+    }
+}
+
+impl From<InputTextMessageContent> for InputMessageContent {
+    fn from(content: InputTextMessageContent) -> InputMessageContent {
+        InputMessageContent::Text(content)
+    }
+}
+
+impl From<InputLocationMessageContent> for InputMessageContent {
+    fn from(content: InputLocationMessageContent) -> InputMessageContent {
+        InputMessageContent::Location(content)
+    }
+}
+
+impl From<InputVenueMessageContent> for InputMessageContent {
+    fn from(content: InputVenueMessageContent) -> InputMessageContent {
+        InputMessageContent::Venue(content)
+    }
+}
+
+impl From<InputContactMessageContent> for InputMessageContent {
+    fn from(content: InputContactMessageContent) -> InputMessageContent {
+        InputMessageContent::Contact(content)
+    }
+}
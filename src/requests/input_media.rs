@@ -1,22 +1,214 @@
+use std::ops::Not;
+use requests::send_message::{FileKind, InputFile, ParseMode};
+use requests::text_entity::TextEntity;
+
 #[derive(Serialize, Debug, Clone)]
-pub struct InputMediaPhoto {}
+pub struct InputMediaPhoto {
+    pub media: FileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub has_spoiler: bool,
+}
 
 #[derive(Serialize, Debug, Clone)]
-pub struct InputMediaVideo {}
+pub struct InputMediaVideo {
+    pub media: FileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub supports_streaming: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub has_spoiler: bool,
+}
 
 #[derive(Serialize, Debug, Clone)]
-pub struct InputMediaAnimation {}
+pub struct InputMediaAnimation {
+    pub media: FileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub has_spoiler: bool,
+}
 
 #[derive(Serialize, Debug, Clone)]
-pub struct InputMediaDocument {}
+pub struct InputMediaDocument {
+    pub media: FileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub disable_content_type_detection: bool,
+}
 
 #[derive(Serialize, Debug, Clone)]
-pub struct InputMediaAudio {}
+pub struct InputMediaAudio {
+    pub media: FileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
 
+/// Thumbnails are always uploaded as new files, telegram doesn't accept file ids or urls for them.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
 pub enum InputMedia {
     Animation(InputMediaAnimation),
     Document(InputMediaDocument),
     Audio(InputMediaAudio),
     Photo(InputMediaPhoto),
     Video(InputMediaVideo),
-}
\ No newline at end of file
+}
+
+impl InputMediaPhoto {
+    pub fn new(media: FileKind) -> InputMediaPhoto {
+        InputMediaPhoto {
+            media,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            has_spoiler: false,
+        }
+    }
+
+    pub(crate) fn files(&self) -> Vec<&InputFile> {
+        self.media.input_file().into_iter().collect()
+    }
+}
+
+impl InputMediaVideo {
+    pub fn new(media: FileKind) -> InputMediaVideo {
+        InputMediaVideo {
+            media,
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: false,
+            has_spoiler: false,
+        }
+    }
+
+    pub(crate) fn files(&self) -> Vec<&InputFile> {
+        files(&self.media, &self.thumbnail)
+    }
+}
+
+impl InputMediaAnimation {
+    pub fn new(media: FileKind) -> InputMediaAnimation {
+        InputMediaAnimation {
+            media,
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            width: None,
+            height: None,
+            duration: None,
+            has_spoiler: false,
+        }
+    }
+
+    pub(crate) fn files(&self) -> Vec<&InputFile> {
+        files(&self.media, &self.thumbnail)
+    }
+}
+
+impl InputMediaDocument {
+    pub fn new(media: FileKind) -> InputMediaDocument {
+        InputMediaDocument {
+            media,
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            disable_content_type_detection: false,
+        }
+    }
+
+    pub(crate) fn files(&self) -> Vec<&InputFile> {
+        files(&self.media, &self.thumbnail)
+    }
+}
+
+impl InputMediaAudio {
+    pub fn new(media: FileKind) -> InputMediaAudio {
+        InputMediaAudio {
+            media,
+            thumbnail: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            duration: None,
+            performer: None,
+            title: None,
+        }
+    }
+
+    pub(crate) fn files(&self) -> Vec<&InputFile> {
+        files(&self.media, &self.thumbnail)
+    }
+}
+
+impl InputMedia {
+    pub(crate) fn files(&self) -> Vec<&InputFile> {
+        match self {
+            InputMedia::Animation(animation) => animation.files(),
+            InputMedia::Document(document) => document.files(),
+            InputMedia::Audio(audio) => audio.files(),
+            InputMedia::Photo(photo) => photo.files(),
+            InputMedia::Video(video) => video.files(),
+        }
+    }
+}
+
+fn files<'a>(media: &'a FileKind, thumbnail: &'a Option<InputFile>) -> Vec<&'a InputFile> {
+    media.input_file().into_iter().chain(thumbnail).collect()
+}
@@ -0,0 +1,96 @@
+use std::ops::Not;
+
+use chrono::{DateTime, Utc};
+
+use requests::Request;
+use requests::chat_id::ChatId;
+use super::chat_member::serialize_date;
+
+/// Revokes the current primary link and returns the new one.
+#[derive(Serialize, Debug, Clone)]
+pub struct ExportChatInviteLinkRequest {
+    pub chat_id: ChatId,
+}
+
+/// `member_limit` can't be combined with `creates_join_request`.
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateChatInviteLinkRequest {
+    pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_date")]
+    pub expire_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub creates_join_request: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EditChatInviteLinkRequest {
+    pub chat_id: ChatId,
+    pub invite_link: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_date")]
+    pub expire_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub creates_join_request: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RevokeChatInviteLinkRequest {
+    pub chat_id: ChatId,
+    pub invite_link: String,
+}
+
+impl CreateChatInviteLinkRequest {
+    pub fn new(chat_id: ChatId) -> CreateChatInviteLinkRequest {
+        CreateChatInviteLinkRequest {
+            chat_id,
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: false,
+        }
+    }
+}
+
+impl EditChatInviteLinkRequest {
+    pub fn new(chat_id: ChatId, invite_link: String) -> EditChatInviteLinkRequest {
+        EditChatInviteLinkRequest {
+            chat_id,
+            invite_link,
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: false,
+        }
+    }
+}
+
+impl Request for ExportChatInviteLinkRequest {
+    fn method(&self) -> &'static str {
+        "exportChatInviteLink"
+    }
+}
+
+impl Request for CreateChatInviteLinkRequest {
+    fn method(&self) -> &'static str {
+        "createChatInviteLink"
+    }
+}
+
+impl Request for EditChatInviteLinkRequest {
+    fn method(&self) -> &'static str {
+        "editChatInviteLink"
+    }
+}
+
+impl Request for RevokeChatInviteLinkRequest {
+    fn method(&self) -> &'static str {
+        "revokeChatInviteLink"
+    }
+}
@@ -0,0 +1,180 @@
+use requests::reply_markup::*;
+use error::KeyboardError;
+
+pub const MAX_BUTTONS_IN_ROW: usize = 8;
+pub const MAX_BUTTONS: usize = 100;
+
+#[derive(Debug, Clone)]
+pub struct InlineKeyboardBuilder {
+    rows: Rows<InlineKeyboardButton>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplyKeyboardBuilder {
+    rows: Rows<KeyboardButton>,
+    resize_keyboard: bool,
+    one_time_keyboard: bool,
+    selective: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Rows<T> {
+    columns: Option<usize>,
+    rows: Vec<Vec<T>>,
+    row_closed: bool,
+}
+
+impl InlineKeyboardBuilder {
+    pub fn new() -> InlineKeyboardBuilder {
+        InlineKeyboardBuilder {
+            rows: Rows::new(),
+        }
+    }
+
+    pub fn columns(mut self, columns: usize) -> InlineKeyboardBuilder {
+        self.rows.columns = Some(columns);
+        self
+    }
+
+    pub fn button(mut self, button: InlineKeyboardButton) -> InlineKeyboardBuilder {
+        self.rows.push(button);
+        self
+    }
+
+    pub fn buttons<I: IntoIterator<Item=InlineKeyboardButton>>(mut self, buttons: I) -> InlineKeyboardBuilder {
+        for button in buttons {
+            self.rows.push(button);
+        }
+        self
+    }
+
+    pub fn row(mut self, row: Vec<InlineKeyboardButton>) -> InlineKeyboardBuilder {
+        self.rows.push_row(row);
+        self
+    }
+
+    pub fn next_row(mut self) -> InlineKeyboardBuilder {
+        self.rows.row_closed = true;
+        self
+    }
+
+    pub fn build(self) -> Result<InlineKeyboard, KeyboardError> {
+        self.rows.validate()
+            .map(|inline_keyboard| InlineKeyboard { inline_keyboard })
+    }
+}
+
+impl Default for InlineKeyboardBuilder {
+    fn default() -> Self {
+        InlineKeyboardBuilder::new()
+    }
+}
+
+impl ReplyKeyboardBuilder {
+    pub fn new() -> ReplyKeyboardBuilder {
+        ReplyKeyboardBuilder {
+            rows: Rows::new(),
+            resize_keyboard: false,
+            one_time_keyboard: false,
+            selective: false,
+        }
+    }
+
+    pub fn columns(mut self, columns: usize) -> ReplyKeyboardBuilder {
+        self.rows.columns = Some(columns);
+        self
+    }
+
+    pub fn button(mut self, button: KeyboardButton) -> ReplyKeyboardBuilder {
+        self.rows.push(button);
+        self
+    }
+
+    pub fn buttons<I: IntoIterator<Item=KeyboardButton>>(mut self, buttons: I) -> ReplyKeyboardBuilder {
+        for button in buttons {
+            self.rows.push(button);
+        }
+        self
+    }
+
+    pub fn row(mut self, row: Vec<KeyboardButton>) -> ReplyKeyboardBuilder {
+        self.rows.push_row(row);
+        self
+    }
+
+    pub fn next_row(mut self) -> ReplyKeyboardBuilder {
+        self.rows.row_closed = true;
+        self
+    }
+
+    pub fn resize_keyboard(mut self) -> ReplyKeyboardBuilder {
+        self.resize_keyboard = true;
+        self
+    }
+
+    pub fn one_time_keyboard(mut self) -> ReplyKeyboardBuilder {
+        self.one_time_keyboard = true;
+        self
+    }
+
+    pub fn selective(mut self) -> ReplyKeyboardBuilder {
+        self.selective = true;
+        self
+    }
+
+    pub fn build(self) -> Result<ReplyKeyboardMarkup, KeyboardError> {
+        let ReplyKeyboardBuilder { rows, resize_keyboard, one_time_keyboard, selective } = self;
+        rows.validate()
+            .map(|keyboard| ReplyKeyboardMarkup {
+                keyboard,
+                resize_keyboard,
+                one_time_keyboard,
+                selective,
+            })
+    }
+}
+
+impl Default for ReplyKeyboardBuilder {
+    fn default() -> Self {
+        ReplyKeyboardBuilder::new()
+    }
+}
+
+impl<T> Rows<T> {
+    fn new() -> Rows<T> {
+        Rows {
+            columns: None,
+            rows: Vec::new(),
+            row_closed: true,
+        }
+    }
+
+    fn push(&mut self, button: T) {
+        let wrap =
+            match (self.rows.last(), self.columns) {
+                (Some(row), Some(columns)) => row.len() >= columns,
+                _ => false,
+            };
+        if self.row_closed || wrap {
+            self.rows.push(Vec::new());
+            self.row_closed = false;
+        }
+        self.rows.last_mut().expect("Keyboard row must exist").push(button);
+    }
+
+    fn push_row(&mut self, row: Vec<T>) {
+        self.rows.push(row);
+        self.row_closed = true;
+    }
+
+    fn validate(self) -> Result<Vec<Vec<T>>, KeyboardError> {
+        if let Some((row, count)) = self.rows.iter().map(Vec::len).enumerate().find(|&(_, count)| count > MAX_BUTTONS_IN_ROW) {
+            return Err(KeyboardError::TooManyButtonsInRow { row, count });
+        }
+        let count = self.rows.iter().map(Vec::len).sum();
+        if count > MAX_BUTTONS {
+            return Err(KeyboardError::TooManyButtons { count });
+        }
+        Ok(self.rows)
+    }
+}
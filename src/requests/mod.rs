@@ -9,6 +9,19 @@ pub use self::get_file::*;
 pub use self::reply_markup::*;
 pub use self::answer_callback_query::*;
 pub use self::chat_action::*;
+pub use self::keyboard_builder::*;
+pub use self::edit_message::*;
+pub use self::delete_message::*;
+pub use self::copy_message::*;
+pub use self::get_chat::*;
+pub use self::chat_member::*;
+pub use self::chat_settings::*;
+pub use self::invite_link::*;
+pub use self::forum_topic::*;
+pub use self::set_message_reaction::*;
+pub use self::answer_inline_query::*;
+pub use self::inline_query_result::*;
+pub use self::text_entity::{TextEntity, TextEntityKind};
 
 mod send_message;
 mod get_updates;
@@ -21,6 +34,23 @@ mod get_file;
 mod reply_markup;
 mod answer_callback_query;
 mod chat_action;
+mod keyboard_builder;
+mod edit_message;
+mod delete_message;
+mod copy_message;
+mod get_chat;
+mod chat_member;
+mod chat_settings;
+mod invite_link;
+mod forum_topic;
+mod set_message_reaction;
+mod answer_inline_query;
+mod inline_query_result;
+pub(crate) mod text_entity;
 pub(crate) trait Request {
     fn method(&self) -> &'static str;
+
+    fn files(&self) -> Vec<&InputFile> {
+        Vec::new()
+    }
 }
\ No newline at end of file
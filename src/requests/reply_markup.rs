@@ -1,4 +1,7 @@
 use std::ops::Not;
+use serde::Serialize;
+use callback_data::{self, CallbackDataStore};
+use error::CallbackDataError;
 
 #[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
@@ -69,4 +72,110 @@ pub struct KeyboardButton {
     pub request_contact: bool,
     #[serde(skip_serializing_if = "Not::not")]
     pub request_location: bool,
+}
+
+impl InlineKeyboardButton {
+    pub fn url(text: String, url: String) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            url: Some(url),
+            ..InlineKeyboardButton::new(text)
+        }
+    }
+
+    pub fn callback(text: String, callback_data: String) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            callback_data: Some(callback_data),
+            ..InlineKeyboardButton::new(text)
+        }
+    }
+
+    pub fn encoded_callback<T: Serialize>(text: String, data: &T) -> Result<InlineKeyboardButton, CallbackDataError> {
+        callback_data::encode(data).map(|data| InlineKeyboardButton::callback(text, data))
+    }
+
+    pub fn encoded_callback_with_store<T, S>(text: String, data: &T, store: &S) -> Result<InlineKeyboardButton, CallbackDataError>
+        where T: Serialize,
+              S: CallbackDataStore + ?Sized {
+        callback_data::encode_with_store(data, store).map(|data| InlineKeyboardButton::callback(text, data))
+    }
+
+    pub fn switch_inline_query(text: String, query: String) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            switch_inline_query: Some(query),
+            ..InlineKeyboardButton::new(text)
+        }
+    }
+
+    pub fn switch_inline_query_current_chat(text: String, query: String) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            switch_inline_query_current_chat: Some(query),
+            ..InlineKeyboardButton::new(text)
+        }
+    }
+
+    pub fn pay(text: String) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            pay: true,
+            ..InlineKeyboardButton::new(text)
+        }
+    }
+
+    fn new(text: String) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            text,
+            url: None,
+            callback_data: None,
+            switch_inline_query: None,
+            switch_inline_query_current_chat: None,
+            pay: false,
+        }
+    }
+}
+
+impl KeyboardButton {
+    pub fn new(text: String) -> KeyboardButton {
+        KeyboardButton {
+            text,
+            request_contact: false,
+            request_location: false,
+        }
+    }
+
+    pub fn request_contact(text: String) -> KeyboardButton {
+        KeyboardButton {
+            request_contact: true,
+            ..KeyboardButton::new(text)
+        }
+    }
+
+    pub fn request_location(text: String) -> KeyboardButton {
+        KeyboardButton {
+            request_location: true,
+            ..KeyboardButton::new(text)
+        }
+    }
+}
+
+impl From<InlineKeyboard> for ReplyMarkup {
+    fn from(keyboard: InlineKeyboard) -> Self {
+        ReplyMarkup::InlineKeyboard(keyboard)
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(keyboard: ReplyKeyboardMarkup) -> Self {
+        ReplyMarkup::ReplyKeyboardMarkup(keyboard)
+    }
+}
+
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(remove: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(remove)
+    }
+}
+
+impl From<ForceReply> for ReplyMarkup {
+    fn from(force_reply: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(force_reply)
+    }
 }
\ No newline at end of file
@@ -1,12 +1,14 @@
 use requests::chat_id::ChatId;
-use requests::input_media::InputMediaPhoto;
-use requests::input_media::InputMediaVideo;
+use requests::input_media::*;
+use requests::send_message::InputFile;
 use std::ops::Not;
 use requests::Request;
 
 #[derive(Serialize, Debug, Clone)]
 pub struct SendMediaGroupRequest {
     pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     pub media: Vec<InputMediaGroup>,
     #[serde(skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
@@ -14,15 +16,41 @@ pub struct SendMediaGroupRequest {
     pub reply_to_message_id: Option<i64>,
 }
 
-#[serde(tag = "type")]
+/// Documents and audios can only be grouped with media of the same type, photos and videos can be mixed.
 #[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
 pub enum InputMediaGroup {
     Photo(InputMediaPhoto),
     Video(InputMediaVideo),
+    Document(InputMediaDocument),
+    Audio(InputMediaAudio),
+}
+
+impl SendMediaGroupRequest {
+    pub fn new(chat_id: ChatId, media: Vec<InputMediaGroup>) -> SendMediaGroupRequest {
+        SendMediaGroupRequest {
+            chat_id,
+            message_thread_id: None,
+            media,
+            disable_notification: false,
+            reply_to_message_id: None,
+        }
+    }
 }
 
 impl Request for SendMediaGroupRequest {
     fn method(&self) -> &'static str {
         "sendMediaGroup"
     }
-}
\ No newline at end of file
+
+    fn files(&self) -> Vec<&InputFile> {
+        self.media.iter()
+            .flat_map(|media| match media {
+                InputMediaGroup::Photo(photo) => photo.files(),
+                InputMediaGroup::Video(video) => video.files(),
+                InputMediaGroup::Document(document) => document.files(),
+                InputMediaGroup::Audio(audio) => audio.files(),
+            })
+            .collect()
+    }
+}
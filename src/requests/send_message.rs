@@ -1,11 +1,15 @@
 use std::ops::Not;
+use serde::{Serialize, Serializer};
 use requests::Request;
 use requests::chat_id::ChatId;
 use requests::reply_markup::ReplyMarkup;
+use requests::text_entity::TextEntity;
 
 #[derive(Serialize, Debug, Clone)]
 pub struct SendMessageRequest {
     pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     #[serde(flatten)]
     pub kind: SendMessageKind,
     #[serde(skip_serializing_if = "Not::not")]
@@ -21,6 +25,8 @@ pub struct SendText {
     pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<TextEntity>>,
     #[serde(skip_serializing_if = "Not::not")]
     pub disable_web_page_preview: bool,
 }
@@ -32,6 +38,8 @@ pub struct SendPhoto {
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -42,6 +50,8 @@ pub struct SendAudio {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub performer: Option<String>,
@@ -56,6 +66,8 @@ pub struct SendDocument {
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -71,6 +83,8 @@ pub struct SendVideo {
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
     #[serde(skip_serializing_if = "Not::not")]
     pub supports_streaming: bool,
 }
@@ -88,6 +102,8 @@ pub struct SendAnimation {
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -98,6 +114,8 @@ pub struct SendVoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<TextEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
 }
 
@@ -160,13 +178,28 @@ pub enum SendMessageKind {
 pub enum FileKind {
     FileId(String),
     Url(String),
-    InputFile(Vec<u8>)
+    InputFile(InputFile)
+}
+
+/// File uploaded with multipart/form-data. `name` is used both as the file name and as the `attach://` reference,
+/// so it must be unique within a request. Quotes are encoded and line breaks are removed from it, as they would break the part header.
+#[derive(Debug, Clone)]
+pub struct InputFile {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl InputFile {
+    pub(crate) fn part_name(&self) -> String {
+        self.name.replace('"', "%22").replace(['\r', '\n'], "")
+    }
 }
 
 #[derive(Serialize, Debug, Clone, Copy)]
 pub enum ParseMode {
     Html,
     Markdown,
+    MarkdownV2,
 }
 
 
@@ -174,6 +207,7 @@ impl SendMessageRequest {
     pub fn new(chat_id: ChatId, kind: SendMessageKind) -> SendMessageRequest {
         SendMessageRequest {
             chat_id,
+            message_thread_id: None,
             kind,
             disable_notification: false,
             reply_to_message_id: None,
@@ -187,6 +221,7 @@ impl SendText {
         SendText {
             text,
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
         }
     }
@@ -198,6 +233,7 @@ impl SendPhoto {
             photo,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
         }
     }
 }
@@ -211,6 +247,7 @@ impl SendAnimation {
             height: None,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
         }
     }
 }
@@ -221,6 +258,7 @@ impl SendAudio {
             audio,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             performer: None,
             title: None,
@@ -245,6 +283,7 @@ impl SendDocument {
             document,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
         }
     }
 }
@@ -291,6 +330,7 @@ impl SendVideo {
             height: None,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             supports_streaming: false,
         }
     }
@@ -302,11 +342,29 @@ impl SendVoice {
             voice,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
         }
     }
 }
 
+impl FileKind {
+    pub(crate) fn input_file(&self) -> Option<&InputFile> {
+        match self {
+            FileKind::InputFile(file) =>
+                Some(file),
+            _ =>
+                None
+        }
+    }
+}
+
+impl Serialize for InputFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("attach://{}", self.part_name()))
+    }
+}
+
 impl Request for SendMessageRequest {
     fn method(&self) -> &'static str {
         match self.kind {
@@ -323,4 +381,19 @@ impl Request for SendMessageRequest {
             SendMessageKind::Contact(_) => "sendContact",
         }
     }
+
+    fn files(&self) -> Vec<&InputFile> {
+        let file =
+            match self.kind {
+                SendMessageKind::Photo(ref photo) => &photo.photo,
+                SendMessageKind::Audio(ref audio) => &audio.audio,
+                SendMessageKind::Document(ref document) => &document.document,
+                SendMessageKind::Video(ref video) => &video.video,
+                SendMessageKind::Animation(ref animation) => &animation.animation,
+                SendMessageKind::Voice(ref voice) => &voice.voice,
+                SendMessageKind::VideoNote(ref video_note) => &video_note.video_note,
+                _ => return Vec::new(),
+            };
+        file.input_file().into_iter().collect()
+    }
 }
\ No newline at end of file
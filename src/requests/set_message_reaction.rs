@@ -0,0 +1,66 @@
+use std::ops::Not;
+
+use requests::Request;
+use requests::chat_id::ChatId;
+
+/// Reaction set by the bot. Unlike received `ReactionType` it has no paid and unknown variants, bots can't set those.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Reaction {
+    Emoji { emoji: String },
+    CustomEmoji { custom_emoji_id: String },
+}
+
+/// Replaces reactions of the bot on the message, an empty `reaction` removes them.
+#[derive(Serialize, Debug, Clone)]
+pub struct SetMessageReactionRequest {
+    pub chat_id: ChatId,
+    pub message_id: i64,
+    pub reaction: Vec<Reaction>,
+    #[serde(skip_serializing_if = "Not::not")]
+    pub is_big: bool,
+}
+
+impl SetMessageReactionRequest {
+    pub fn new(chat_id: ChatId, message_id: i64, reaction: Vec<Reaction>) -> SetMessageReactionRequest {
+        SetMessageReactionRequest {
+            chat_id,
+            message_id,
+            reaction,
+            is_big: false,
+        }
+    }
+}
+
+impl Reaction {
+    pub fn emoji<S: Into<String>>(emoji: S) -> Reaction {
+        Reaction::Emoji { emoji: emoji.into() }
+    }
+
+    pub fn custom_emoji<S: Into<String>>(custom_emoji_id: S) -> Reaction {
+        Reaction::CustomEmoji { custom_emoji_id: custom_emoji_id.into() }
+    }
+}
+
+impl Request for SetMessageReactionRequest {
+    fn method(&self) -> &'static str {
+        "setMessageReaction"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn serializes_reactions_with_type_tag() {
+        let request = SetMessageReactionRequest::new(ChatId::Id(1), 2, vec![Reaction::emoji("👍"), Reaction::custom_emoji("3")]);
+
+        assert_eq!(serde_json::to_value(&request).expect("Request is serialized"), serde_json::json!({
+            "chat_id": 1,
+            "message_id": 2,
+            "reaction": [{ "type": "emoji", "emoji": "👍" }, { "type": "custom_emoji", "custom_emoji_id": "3" }],
+        }));
+    }
+}
@@ -0,0 +1,67 @@
+use std::ops::Range;
+
+use serde::{Serialize, Serializer};
+
+/// Entity of outgoing text. `offset` and `length` are measured in utf-16 code units, use `TextEntity::new` to compute them from a byte range.
+#[derive(Serialize, Debug, Clone)]
+pub struct TextEntity {
+    #[serde(flatten)]
+    pub kind: TextEntityKind,
+    pub offset: usize,
+    pub length: usize,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TextEntityKind {
+    Mention,
+    Hashtag,
+    Cashtag,
+    BotCommand,
+    Url,
+    Email,
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+    ExpandableBlockquote,
+    Code,
+    Pre {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        language: Option<String>
+    },
+    TextLink { url: String },
+    TextMention {
+        #[serde(rename = "user", serialize_with = "serialize_user_id")]
+        user_id: i64
+    },
+    CustomEmoji { custom_emoji_id: String },
+}
+
+impl TextEntity {
+    /// Panics if `range` is out of `text` bounds or does not lie on char boundaries, the same way as slicing does.
+    pub fn new(text: &str, range: Range<usize>, kind: TextEntityKind) -> TextEntity {
+        let offset = utf16_len(&text[..range.start]);
+        let length = utf16_len(&text[range]);
+        TextEntity {
+            kind,
+            offset,
+            length,
+        }
+    }
+}
+
+pub(crate) fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+fn serialize_user_id<S: Serializer>(user_id: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct User {
+        id: i64
+    }
+    User { id: *user_id }.serialize(serializer)
+}
@@ -0,0 +1,30 @@
+use responses::message::{Caption, Message};
+use responses::update::Update;
+
+/// Messages sent together as a media group, sorted by message id.
+#[derive(Clone, Debug)]
+pub struct Album {
+    pub media_group_id: String,
+    pub messages: Vec<Message>,
+}
+
+#[derive(Clone, Debug)]
+pub enum AlbumUpdate {
+    Update(Box<Update>),
+    Album(Album),
+}
+
+impl Album {
+    pub fn chat_id(&self) -> i64 {
+        self.messages[0].chat_id()
+    }
+
+    /// Telegram shows the caption of the first captioned item under the whole album.
+    pub fn caption(&self) -> Option<&Caption> {
+        self.messages.iter().filter_map(Message::caption).next()
+    }
+
+    pub fn captions(&self) -> Vec<Option<&Caption>> {
+        self.messages.iter().map(Message::caption).collect()
+    }
+}
@@ -1,7 +1,10 @@
 use super::raw::chat;
 use error::UnexpectedResponse;
+use responses::message::Message;
 use try_from::TryFrom;
 
+pub use super::raw::chat::{ChatPhoto, ChatPermissions, ForumTopic};
+
 #[derive(Clone, Debug)]
 pub struct Chat {
     pub id: i64,
@@ -22,13 +25,36 @@ pub enum ChatKind {
     SuperGroup {
         title: String,
         username: Option<String>,
+        /// Messages are organized into topics, see `Message::message_thread_id`.
+        is_forum: bool,
     },
+    Channel {
+        title: String,
+        username: Option<String>,
+    },
+}
+
+/// Chat with all the info returned by `getChat`.
+#[derive(Clone, Debug)]
+pub struct ChatFullInfo {
+    pub chat: Chat,
+    pub photo: Option<ChatPhoto>,
+    pub bio: Option<String>,
+    pub description: Option<String>,
+    pub invite_link: Option<String>,
+    pub pinned_message: Option<Box<Message>>,
+    pub permissions: Option<ChatPermissions>,
+    pub slow_mode_delay: Option<i64>,
+    pub linked_chat_id: Option<i64>,
+    pub sticker_set_name: Option<String>,
+    pub can_set_sticker_set: bool,
 }
 
 impl TryFrom<chat::Chat> for Chat {
     type Error = UnexpectedResponse;
 
     fn try_from(chat: chat::Chat) -> Result<Self, UnexpectedResponse> {
+        let is_forum = chat.is_forum.unwrap_or(false);
         let chat =
             match (chat.id, chat.username, chat.first_name, chat.last_name, chat.all_members_are_administrators, chat.title, chat.typ.as_ref()) {
                 (id, username, Some(first_name), last_name, _, _, "private") =>
@@ -54,11 +80,57 @@ impl TryFrom<chat::Chat> for Chat {
                         kind: ChatKind::SuperGroup {
                             title,
                             username,
+                            is_forum,
+                        },
+                    },
+                (id, username, _, _, _, Some(title), "channel") =>
+                    Chat {
+                        id,
+                        kind: ChatKind::Channel {
+                            title,
+                            username,
                         },
                     },
                 _ =>
-                    return Err(UnexpectedResponse::ConvertError(String::from("Wrong chat. Excepted one of this: private, group or supergroup")))
+                    return Err(UnexpectedResponse::ConvertError(String::from("Wrong chat. Excepted one of this: private, group, supergroup or channel")))
             };
         Ok(chat)
     }
-}
\ No newline at end of file
+}
+
+impl TryFrom<chat::Chat> for ChatFullInfo {
+    type Error = UnexpectedResponse;
+
+    fn try_from(mut chat: chat::Chat) -> Result<Self, UnexpectedResponse> {
+        let pinned_message =
+            match chat.pinned_message.take() {
+                Some(message) =>
+                    Some(Box::new(Message::try_from(*message)?)),
+                None =>
+                    None
+            };
+        let photo = chat.photo.take();
+        let bio = chat.bio.take();
+        let description = chat.description.take();
+        let invite_link = chat.invite_link.take();
+        let permissions = chat.permissions.take();
+        let slow_mode_delay = chat.slow_mode_delay;
+        let linked_chat_id = chat.linked_chat_id;
+        let sticker_set_name = chat.sticker_set_name.take();
+        let can_set_sticker_set = chat.can_set_sticker_set.unwrap_or(false);
+        Chat::try_from(chat)
+            .map(|chat| ChatFullInfo {
+                chat,
+                photo,
+                bio,
+                description,
+                invite_link,
+                pinned_message,
+                permissions,
+                slow_mode_delay,
+                linked_chat_id,
+                sticker_set_name,
+                can_set_sticker_set,
+            })
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::prelude::*;
+
+use super::raw;
+use error::UnexpectedResponse;
+use responses::chat::Chat;
+use responses::user::User;
+use try_from::TryFrom;
+
+#[derive(Clone, Debug)]
+pub struct ChatInviteLink {
+    pub invite_link: String,
+    pub creator: User,
+    pub creates_join_request: bool,
+    pub is_primary: bool,
+    pub is_revoked: bool,
+    pub name: Option<String>,
+    pub expire_date: Option<DateTime<Utc>>,
+    pub member_limit: Option<i64>,
+    pub pending_join_request_count: Option<i64>,
+}
+
+/// `user_chat_id` is a private chat with the applicant, the bot can write there until the request is handled.
+#[derive(Clone, Debug)]
+pub struct ChatJoinRequest {
+    pub chat: Chat,
+    pub from: User,
+    pub user_chat_id: i64,
+    pub date: DateTime<Utc>,
+    pub bio: Option<String>,
+    pub invite_link: Option<ChatInviteLink>,
+}
+
+impl TryFrom<raw::chat::ChatInviteLink> for ChatInviteLink {
+    type Error = UnexpectedResponse;
+
+    fn try_from(link: raw::chat::ChatInviteLink) -> Result<Self, UnexpectedResponse> {
+        let expire_date =
+            match link.expire_date {
+                Some(date) =>
+                    Some(Utc.timestamp_opt(date, 0).single()
+                        .ok_or_else(|| UnexpectedResponse::ConvertError(format!("Invalid invite link expire date: {}", date)))?),
+                None =>
+                    None
+            };
+        Ok(ChatInviteLink {
+            invite_link: link.invite_link,
+            creator: link.creator,
+            creates_join_request: link.creates_join_request,
+            is_primary: link.is_primary,
+            is_revoked: link.is_revoked,
+            name: link.name,
+            expire_date,
+            member_limit: link.member_limit,
+            pending_join_request_count: link.pending_join_request_count,
+        })
+    }
+}
+
+impl TryFrom<raw::chat::ChatJoinRequest> for ChatJoinRequest {
+    type Error = UnexpectedResponse;
+
+    fn try_from(request: raw::chat::ChatJoinRequest) -> Result<Self, UnexpectedResponse> {
+        let date = request.date;
+        let invite_link =
+            match request.invite_link {
+                Some(link) =>
+                    Some(ChatInviteLink::try_from(link)?),
+                None =>
+                    None
+            };
+        Ok(ChatJoinRequest {
+            chat: Chat::try_from(request.chat)?,
+            from: request.from,
+            user_chat_id: request.user_chat_id,
+            date: Utc.timestamp_opt(date, 0).single()
+                .ok_or_else(|| UnexpectedResponse::ConvertError(format!("Invalid chat join request date: {}", date)))?,
+            bio: request.bio,
+            invite_link,
+        })
+    }
+}
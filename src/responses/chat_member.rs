@@ -0,0 +1,216 @@
+use chrono::prelude::*;
+
+use super::raw;
+use error::UnexpectedResponse;
+use responses::chat::{Chat, ChatPermissions};
+use responses::chat_invite_link::ChatInviteLink;
+use responses::user::User;
+use try_from::TryFrom;
+
+/// Change of a chat member status, `invite_link` is set if the user joined by a link.
+#[derive(Clone, Debug)]
+pub struct ChatMemberUpdated {
+    pub chat: Chat,
+    pub from: User,
+    pub date: DateTime<Utc>,
+    pub old_chat_member: ChatMember,
+    pub new_chat_member: ChatMember,
+    pub invite_link: Option<ChatInviteLink>,
+    pub via_join_request: bool,
+    pub via_chat_folder_invite_link: bool,
+}
+
+/// `until_date` is `None` when the restriction or ban is forever.
+#[derive(Clone, Debug)]
+pub enum ChatMember {
+    Creator {
+        user: User,
+        is_anonymous: bool,
+        custom_title: Option<String>,
+    },
+    Administrator {
+        user: User,
+        can_be_edited: bool,
+        custom_title: Option<String>,
+        rights: ChatAdministratorRights,
+    },
+    Member {
+        user: User,
+        until_date: Option<DateTime<Utc>>,
+    },
+    Restricted {
+        user: User,
+        is_member: bool,
+        permissions: ChatPermissions,
+        until_date: Option<DateTime<Utc>>,
+    },
+    Left {
+        user: User,
+    },
+    Kicked {
+        user: User,
+        until_date: Option<DateTime<Utc>>,
+    },
+}
+
+/// Missing rights are denied.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ChatAdministratorRights {
+    pub is_anonymous: bool,
+    pub can_manage_chat: bool,
+    pub can_delete_messages: bool,
+    pub can_manage_video_chats: bool,
+    pub can_restrict_members: bool,
+    pub can_promote_members: bool,
+    pub can_change_info: bool,
+    pub can_invite_users: bool,
+    pub can_post_messages: bool,
+    pub can_edit_messages: bool,
+    pub can_pin_messages: bool,
+    pub can_post_stories: bool,
+    pub can_edit_stories: bool,
+    pub can_delete_stories: bool,
+    pub can_manage_topics: bool,
+}
+
+impl ChatMember {
+    pub fn user(&self) -> &User {
+        match self {
+            ChatMember::Creator { user, .. } |
+            ChatMember::Administrator { user, .. } |
+            ChatMember::Member { user, .. } |
+            ChatMember::Restricted { user, .. } |
+            ChatMember::Left { user } |
+            ChatMember::Kicked { user, .. } =>
+                user
+        }
+    }
+
+    pub fn is_admin(&self) -> bool {
+        matches!(self, ChatMember::Creator { .. } | ChatMember::Administrator { .. })
+    }
+
+    /// Restricted users are members only if they have not left the chat.
+    pub fn is_member(&self) -> bool {
+        match self {
+            ChatMember::Creator { .. } | ChatMember::Administrator { .. } | ChatMember::Member { .. } =>
+                true,
+            ChatMember::Restricted { is_member, .. } =>
+                *is_member,
+            ChatMember::Left { .. } | ChatMember::Kicked { .. } =>
+                false
+        }
+    }
+}
+
+impl TryFrom<raw::chat_member::ChatMember> for ChatMember {
+    type Error = UnexpectedResponse;
+
+    fn try_from(member: raw::chat_member::ChatMember) -> Result<Self, UnexpectedResponse> {
+        fn flag(value: Option<bool>) -> bool {
+            value.unwrap_or(false)
+        }
+
+        let until_date =
+            member.until_date
+                .filter(|&date| date != 0)
+                .and_then(|date| Utc.timestamp_opt(date, 0).single());
+        let user = member.user;
+        let member =
+            match member.status.as_ref() {
+                "creator" =>
+                    ChatMember::Creator {
+                        user,
+                        is_anonymous: flag(member.is_anonymous),
+                        custom_title: member.custom_title,
+                    },
+                "administrator" =>
+                    ChatMember::Administrator {
+                        user,
+                        can_be_edited: flag(member.can_be_edited),
+                        custom_title: member.custom_title,
+                        rights: ChatAdministratorRights {
+                            is_anonymous: flag(member.is_anonymous),
+                            can_manage_chat: flag(member.can_manage_chat),
+                            can_delete_messages: flag(member.can_delete_messages),
+                            can_manage_video_chats: flag(member.can_manage_video_chats),
+                            can_restrict_members: flag(member.can_restrict_members),
+                            can_promote_members: flag(member.can_promote_members),
+                            can_change_info: flag(member.can_change_info),
+                            can_invite_users: flag(member.can_invite_users),
+                            can_post_messages: flag(member.can_post_messages),
+                            can_edit_messages: flag(member.can_edit_messages),
+                            can_pin_messages: flag(member.can_pin_messages),
+                            can_post_stories: flag(member.can_post_stories),
+                            can_edit_stories: flag(member.can_edit_stories),
+                            can_delete_stories: flag(member.can_delete_stories),
+                            can_manage_topics: flag(member.can_manage_topics),
+                        },
+                    },
+                "member" =>
+                    ChatMember::Member {
+                        user,
+                        until_date,
+                    },
+                "restricted" =>
+                    ChatMember::Restricted {
+                        user,
+                        is_member: flag(member.is_member),
+                        permissions: ChatPermissions {
+                            can_send_messages: flag(member.can_send_messages),
+                            can_send_audios: flag(member.can_send_audios),
+                            can_send_documents: flag(member.can_send_documents),
+                            can_send_photos: flag(member.can_send_photos),
+                            can_send_videos: flag(member.can_send_videos),
+                            can_send_video_notes: flag(member.can_send_video_notes),
+                            can_send_voice_notes: flag(member.can_send_voice_notes),
+                            can_send_polls: flag(member.can_send_polls),
+                            can_send_other_messages: flag(member.can_send_other_messages),
+                            can_add_web_page_previews: flag(member.can_add_web_page_previews),
+                            can_change_info: flag(member.can_change_info),
+                            can_invite_users: flag(member.can_invite_users),
+                            can_pin_messages: flag(member.can_pin_messages),
+                            can_manage_topics: flag(member.can_manage_topics),
+                        },
+                        until_date,
+                    },
+                "left" =>
+                    ChatMember::Left { user },
+                "kicked" =>
+                    ChatMember::Kicked {
+                        user,
+                        until_date,
+                    },
+                status =>
+                    return Err(UnexpectedResponse::ConvertError(format!("Unknown chat member status: {}", status)))
+            };
+        Ok(member)
+    }
+}
+
+impl TryFrom<raw::chat_member::ChatMemberUpdated> for ChatMemberUpdated {
+    type Error = UnexpectedResponse;
+
+    fn try_from(updated: raw::chat_member::ChatMemberUpdated) -> Result<Self, UnexpectedResponse> {
+        let invite_link =
+            match updated.invite_link {
+                Some(link) =>
+                    Some(ChatInviteLink::try_from(link)?),
+                None =>
+                    None
+            };
+        let date = updated.date;
+        Ok(ChatMemberUpdated {
+            chat: Chat::try_from(updated.chat)?,
+            from: updated.from,
+            date: Utc.timestamp_opt(date, 0).single()
+                .ok_or_else(|| UnexpectedResponse::ConvertError(format!("Invalid chat member update date: {}", date)))?,
+            old_chat_member: ChatMember::try_from(updated.old_chat_member)?,
+            new_chat_member: ChatMember::try_from(updated.new_chat_member)?,
+            invite_link,
+            via_join_request: updated.via_join_request.unwrap_or(false),
+            via_chat_folder_invite_link: updated.via_chat_folder_invite_link.unwrap_or(false),
+        })
+    }
+}
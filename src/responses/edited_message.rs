@@ -0,0 +1,25 @@
+use responses::message::Message;
+use responses::raw;
+use error::UnexpectedResponse;
+use try_from::TryFrom;
+
+#[derive(Clone, Debug)]
+pub enum EditedMessage {
+    Message(Box<Message>),
+    Inline,
+}
+
+impl TryFrom<raw::message::EditResult> for EditedMessage {
+    type Error = UnexpectedResponse;
+
+    fn try_from(result: raw::message::EditResult) -> Result<Self, UnexpectedResponse> {
+        match result {
+            raw::message::EditResult::Message(message) =>
+                TryFrom::try_from(*message).map(|message| EditedMessage::Message(Box::new(message))),
+            raw::message::EditResult::Inline(true) =>
+                Ok(EditedMessage::Inline),
+            raw::message::EditResult::Inline(false) =>
+                Err(UnexpectedResponse::ConvertError(String::from("Telegram reported that inline message was not edited")))
+        }
+    }
+}
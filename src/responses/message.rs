@@ -7,14 +7,18 @@ use try_from::TryFrom;
 
 use super::channel::*;
 use super::chat::*;
+use super::message_entity::*;
 
 pub use self::raw::message::{Animation,
                              Audio,
                              Contact,
                              Document,
+                             ForumTopicCreated,
+                             ForumTopicEdited,
                              Game,
                              Invoice,
                              Location,
+                             MessageId,
                              PassportData,
                              PhotoSize,
                              Sticker,
@@ -32,6 +36,9 @@ pub struct Message {
     pub forward: Option<Forward>,
     pub edit_date: Option<DateTime<Utc>>,
     pub reply_to_message: Option<Box<Message>>,
+    /// Topic of a forum supergroup the message belongs to.
+    pub message_thread_id: Option<i64>,
+    pub is_topic_message: bool,
     pub kind: MessageKind,
 }
 
@@ -67,24 +74,6 @@ pub enum ForwardFrom {
     },
 }
 
-#[derive(Clone, Debug)]
-pub enum MessageEntity {
-    Mention(String),
-    Hashtag(String),
-    Cashtag(String),
-    BotCommand(String),
-    Url(String),
-    Email(String),
-    PhoneNumber(String),
-    Bold(String),
-    Italic(String),
-    Code(String),
-    Pre(String),
-    TextLink { text: String, link: String },
-    TextMention { mention: String, user: User },
-    Unknown { typ: String, offset: i64, length: i64 },
-}
-
 #[derive(Clone, Debug)]
 pub struct Caption {
     pub caption: String,
@@ -94,8 +83,8 @@ pub struct Caption {
 #[derive(Clone, Debug)]
 pub enum MessageKind {
     Text { text: String, entities: Option<Vec<MessageEntity>> },
-    Audio { audio: Audio },
-    Document { document: Document, caption: Option<Caption> },
+    Audio { audio: Audio, caption: Option<Caption>, media_group_id: Option<String> },
+    Document { document: Document, caption: Option<Caption>, media_group_id: Option<String> },
     Animation { animation: Animation, caption: Option<Caption> },
     Game { game: Game },
     Photo { photo: Vec<PhotoSize>, caption: Option<Caption>, media_group_id: Option<String> },
@@ -121,6 +110,50 @@ pub enum MessageKind {
     SuccessfulPayment { successful_payment: SuccessfulPayment },
     ConnectedWebsite { connected_website: String },
     PassportData { passport_data: PassportData },
+    ForumTopicCreated { forum_topic_created: ForumTopicCreated },
+    ForumTopicEdited { forum_topic_edited: ForumTopicEdited },
+    ForumTopicClosed,
+    ForumTopicReopened,
+    GeneralForumTopicHidden,
+    GeneralForumTopicUnhidden,
+}
+
+impl Message {
+    pub fn chat_id(&self) -> i64 {
+        match self.from {
+            MessageFrom::Channel { ref channel, .. } =>
+                channel.id,
+            MessageFrom::User { ref chat, .. } =>
+                chat.id,
+        }
+    }
+
+    /// Id of the album the message belongs to, only photos, videos, documents and audios can be grouped.
+    pub fn media_group_id(&self) -> Option<&str> {
+        match self.kind {
+            MessageKind::Photo { ref media_group_id, .. } |
+            MessageKind::Video { ref media_group_id, .. } |
+            MessageKind::Document { ref media_group_id, .. } |
+            MessageKind::Audio { ref media_group_id, .. } =>
+                media_group_id.as_ref().map(String::as_str),
+            _ =>
+                None
+        }
+    }
+
+    pub fn caption(&self) -> Option<&Caption> {
+        match self.kind {
+            MessageKind::Photo { ref caption, .. } |
+            MessageKind::Video { ref caption, .. } |
+            MessageKind::Document { ref caption, .. } |
+            MessageKind::Audio { ref caption, .. } |
+            MessageKind::Animation { ref caption, .. } |
+            MessageKind::Voice { ref caption, .. } =>
+                caption.as_ref(),
+            _ =>
+                None
+        }
+    }
 }
 
 impl TryFrom<raw::message::Message> for Message {
@@ -212,63 +245,13 @@ impl TryFrom<raw::message::Message> for Message {
                                      successful_payment: Option<raw::message::SuccessfulPayment>,
                                      connected_website: Option<String>,
                                      passport_data: Option<raw::message::PassportData>,
+                                     forum_topic_created: Option<raw::message::ForumTopicCreated>,
+                                     forum_topic_edited: Option<raw::message::ForumTopicEdited>,
+                                     forum_topic_closed: Option<raw::message::ForumTopicClosed>,
+                                     forum_topic_reopened: Option<raw::message::ForumTopicReopened>,
+                                     general_forum_topic_hidden: Option<raw::message::GeneralForumTopicHidden>,
+                                     general_forum_topic_unhidden: Option<raw::message::GeneralForumTopicUnhidden>,
             ) -> Result<MessageKind, UnexpectedResponse> {
-                fn into_entities(text: &str, entities: Option<Vec<raw::message::MessageEntity>>) -> Option<Vec<MessageEntity>> {
-                    entities.map(|entities| entities.into_iter().map(|entity| {
-                        let utf16_capture: Vec<u16> = text.encode_utf16().skip(entity.offset as usize).take(entity.offset as usize + entity.length as usize).collect();
-                        let captured = String::from_utf16_lossy(&utf16_capture);
-                        match entity.typ.as_ref() {
-                            "mention" =>
-                                MessageEntity::Mention(captured),
-                            "hashtag" =>
-                                MessageEntity::Hashtag(captured),
-                            "cashtag" =>
-                                MessageEntity::Cashtag(captured),
-                            "bot_command" =>
-                                MessageEntity::BotCommand(captured),
-                            "url" =>
-                                MessageEntity::Url(captured),
-                            "email" =>
-                                MessageEntity::Email(captured),
-                            "phone_number" =>
-                                MessageEntity::PhoneNumber(captured),
-                            "bold" =>
-                                MessageEntity::Bold(captured),
-                            "italic" =>
-                                MessageEntity::Italic(captured),
-                            "code" =>
-                                MessageEntity::Code(captured),
-                            "pre" =>
-                                MessageEntity::Pre(captured),
-                            "text_link" =>
-                                if let Some(link) = entity.url {
-                                    MessageEntity::TextLink { text: captured, link }
-                                } else {
-                                    MessageEntity::Unknown {
-                                        typ: entity.typ,
-                                        offset: entity.offset,
-                                        length: entity.length,
-                                    }
-                                }
-                            "text_mention" =>
-                                if let Some(user) = entity.user {
-                                    MessageEntity::TextMention { mention: captured, user: From::from(user) }
-                                } else {
-                                    MessageEntity::Unknown {
-                                        typ: entity.typ,
-                                        offset: entity.offset,
-                                        length: entity.length,
-                                    }
-                                }
-                            _ =>
-                                MessageEntity::Unknown {
-                                    typ: entity.typ,
-                                    offset: entity.offset,
-                                    length: entity.length,
-                                }
-                        }
-                    }).collect())
-                }
                 let caption = caption.map(|caption| {
                     let entities = into_entities(&caption, caption_entities);
                     Caption { caption, entities }
@@ -279,10 +262,10 @@ impl TryFrom<raw::message::Message> for Message {
                     return Ok(MessageKind::Text { text, entities });
                 }
                 if let Some(audio) = audio {
-                    return Ok(MessageKind::Audio { audio });
+                    return Ok(MessageKind::Audio { audio, media_group_id, caption });
                 }
                 if let Some(document) = document {
-                    return Ok(MessageKind::Document { document, caption });
+                    return Ok(MessageKind::Document { document, media_group_id, caption });
                 }
                 if let Some(animation) = animation {
                     return Ok(MessageKind::Animation { animation, caption });
@@ -359,6 +342,24 @@ impl TryFrom<raw::message::Message> for Message {
                 if let Some(passport_data) = passport_data {
                     return Ok(MessageKind::PassportData { passport_data });
                 }
+                if let Some(forum_topic_created) = forum_topic_created {
+                    return Ok(MessageKind::ForumTopicCreated { forum_topic_created });
+                }
+                if let Some(forum_topic_edited) = forum_topic_edited {
+                    return Ok(MessageKind::ForumTopicEdited { forum_topic_edited });
+                }
+                if forum_topic_closed.is_some() {
+                    return Ok(MessageKind::ForumTopicClosed);
+                }
+                if forum_topic_reopened.is_some() {
+                    return Ok(MessageKind::ForumTopicReopened);
+                }
+                if general_forum_topic_hidden.is_some() {
+                    return Ok(MessageKind::GeneralForumTopicHidden);
+                }
+                if general_forum_topic_unhidden.is_some() {
+                    return Ok(MessageKind::GeneralForumTopicUnhidden);
+                }
 
                 return Err(UnexpectedResponse::ConvertError(String::from("Tried all kind of messages. If this happens, probably there is bug in lib")));
             }
@@ -390,6 +391,9 @@ impl TryFrom<raw::message::Message> for Message {
                     new_chat_photo, delete_chat_photo, group_chat_created, supergroup_chat_created,
                     channel_chat_created, migrate_to_chat_id, migrate_from_chat_id, pinned_message, invoice,
                     successful_payment, connected_website, passport_data,
+                    message_thread_id, is_topic_message,
+                    forum_topic_created, forum_topic_edited, forum_topic_closed, forum_topic_reopened,
+                    general_forum_topic_hidden, general_forum_topic_unhidden,
                 } =>
                     try_into_forward(forward_from, forward_from_chat, forward_from_message_id, forward_signature, forward_date)
                         .and_then(move |forward| try_into_reply(reply_to_message).map(|reply| (forward, reply)))
@@ -402,7 +406,9 @@ impl TryFrom<raw::message::Message> for Message {
                                                   new_chat_members, left_chat_member, new_chat_title,
                                                   new_chat_photo, delete_chat_photo, group_chat_created, supergroup_chat_created,
                                                   channel_chat_created, migrate_to_chat_id, migrate_from_chat_id, pinned_message, invoice,
-                                                  successful_payment, connected_website, passport_data)
+                                                  successful_payment, connected_website, passport_data,
+                                                  forum_topic_created, forum_topic_edited, forum_topic_closed, forum_topic_reopened,
+                                                  general_forum_topic_hidden, general_forum_topic_unhidden)
                                 .map(|kind| (forward, reply, kind)))
                         .and_then(|(forward, reply, kind)| try_into_message_from(from, chat, author_signature).map(|from| (forward, reply, kind, from)))
                         .map(|(forward, reply, kind, from)|
@@ -413,6 +419,8 @@ impl TryFrom<raw::message::Message> for Message {
                                 forward,
                                 edit_date: edit_date.map(|x| Utc.timestamp(x as i64, 0)),
                                 reply_to_message: reply.map(Box::new),
+                                message_thread_id,
+                                is_topic_message: is_topic_message.unwrap_or(false),
                                 kind,
                             }),
             }
@@ -0,0 +1,210 @@
+use std::cmp::{max, min};
+
+use responses::raw;
+use responses::user::User;
+
+/// `offset` and `length` are measured in utf-16 code units, as telegram does. `text` is the substring covered by the entity.
+#[derive(Clone, Debug)]
+pub struct MessageEntity {
+    pub offset: i64,
+    pub length: i64,
+    pub text: String,
+    pub kind: MessageEntityKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum MessageEntityKind {
+    Mention,
+    Hashtag,
+    Cashtag,
+    BotCommand,
+    Url,
+    Email,
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+    ExpandableBlockquote,
+    Code,
+    Pre { language: Option<String> },
+    TextLink { url: String },
+    TextMention { user: User },
+    CustomEmoji { custom_emoji_id: String },
+    Unknown { typ: String },
+}
+
+pub(crate) fn into_entities(text: &str, entities: Option<Vec<raw::message::MessageEntity>>) -> Option<Vec<MessageEntity>> {
+    entities.map(|entities| {
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        entities.into_iter().map(|entity| into_entity(&utf16, entity)).collect()
+    })
+}
+
+fn into_entity(utf16: &[u16], entity: raw::message::MessageEntity) -> MessageEntity {
+    let text = capture(utf16, entity.offset, entity.length);
+    let kind =
+        match (entity.typ.as_ref(), entity.url, entity.user, entity.custom_emoji_id) {
+            ("mention", _, _, _) =>
+                MessageEntityKind::Mention,
+            ("hashtag", _, _, _) =>
+                MessageEntityKind::Hashtag,
+            ("cashtag", _, _, _) =>
+                MessageEntityKind::Cashtag,
+            ("bot_command", _, _, _) =>
+                MessageEntityKind::BotCommand,
+            ("url", _, _, _) =>
+                MessageEntityKind::Url,
+            ("email", _, _, _) =>
+                MessageEntityKind::Email,
+            ("phone_number", _, _, _) =>
+                MessageEntityKind::PhoneNumber,
+            ("bold", _, _, _) =>
+                MessageEntityKind::Bold,
+            ("italic", _, _, _) =>
+                MessageEntityKind::Italic,
+            ("underline", _, _, _) =>
+                MessageEntityKind::Underline,
+            ("strikethrough", _, _, _) =>
+                MessageEntityKind::Strikethrough,
+            ("spoiler", _, _, _) =>
+                MessageEntityKind::Spoiler,
+            ("blockquote", _, _, _) =>
+                MessageEntityKind::Blockquote,
+            ("expandable_blockquote", _, _, _) =>
+                MessageEntityKind::ExpandableBlockquote,
+            ("code", _, _, _) =>
+                MessageEntityKind::Code,
+            ("pre", _, _, _) =>
+                MessageEntityKind::Pre { language: entity.language },
+            ("text_link", Some(url), _, _) =>
+                MessageEntityKind::TextLink { url },
+            ("text_mention", _, Some(user), _) =>
+                MessageEntityKind::TextMention { user },
+            ("custom_emoji", _, _, Some(custom_emoji_id)) =>
+                MessageEntityKind::CustomEmoji { custom_emoji_id },
+            _ =>
+                MessageEntityKind::Unknown { typ: entity.typ }
+        };
+    MessageEntity {
+        offset: entity.offset,
+        length: entity.length,
+        text,
+        kind,
+    }
+}
+
+fn capture(utf16: &[u16], offset: i64, length: i64) -> String {
+    let start = min(max(offset, 0) as usize, utf16.len());
+    let end = min(start.saturating_add(max(length, 0) as usize), utf16.len());
+    String::from_utf16_lossy(&utf16[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn raw_entity(typ: &str, offset: i64, length: i64) -> raw::message::MessageEntity {
+        raw::message::MessageEntity {
+            typ: String::from(typ),
+            offset,
+            length,
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+        }
+    }
+
+    fn utf16_len(text: &str) -> i64 {
+        text.encode_utf16().count() as i64
+    }
+
+    proptest! {
+        #[test]
+        fn captures_entity_text(prefix in "\\PC{0,16}", captured in "\\PC{0,16}", suffix in "\\PC{0,16}") {
+            let text = format!("{}{}{}", prefix, captured, suffix);
+            let entity = raw_entity("bold", utf16_len(&prefix), utf16_len(&captured));
+
+            let entities = into_entities(&text, Some(vec![entity])).unwrap();
+
+            prop_assert_eq!(&entities[0].text, &captured);
+            prop_assert_eq!(entities[0].offset, utf16_len(&prefix));
+            prop_assert_eq!(entities[0].length, utf16_len(&captured));
+        }
+
+        #[test]
+        fn captures_every_entity_of_split_text(parts in prop::collection::vec("\\PC{1,8}", 1..8)) {
+            let text = parts.concat();
+            let mut offset = 0;
+            let mut raw_entities = Vec::new();
+            for part in &parts {
+                raw_entities.push(raw_entity("italic", offset, utf16_len(part)));
+                offset += utf16_len(part);
+            }
+
+            let entities = into_entities(&text, Some(raw_entities)).unwrap();
+
+            let captured: Vec<String> = entities.into_iter().map(|entity| entity.text).collect();
+            prop_assert_eq!(captured, parts);
+        }
+
+        #[test]
+        fn never_panics_on_out_of_range_entities(text in "\\PC{0,16}", offset in -8i64..64, length in -8i64..64) {
+            let entities = into_entities(&text, Some(vec![raw_entity("code", offset, length)])).unwrap();
+
+            prop_assert!(utf16_len(&entities[0].text) <= max(length, 0));
+            prop_assert_eq!(entities[0].offset, offset);
+            prop_assert_eq!(entities[0].length, length);
+        }
+    }
+
+    #[test]
+    fn converts_entity_kinds() {
+        let text = "a";
+        let mut pre = raw_entity("pre", 0, 1);
+        pre.language = Some(String::from("rust"));
+        let mut custom_emoji = raw_entity("custom_emoji", 0, 1);
+        custom_emoji.custom_emoji_id = Some(String::from("42"));
+        let mut text_link = raw_entity("text_link", 0, 1);
+        text_link.url = Some(String::from("https://telegram.org"));
+        let raw_entities = vec![
+            raw_entity("underline", 0, 1),
+            raw_entity("strikethrough", 0, 1),
+            raw_entity("spoiler", 0, 1),
+            raw_entity("blockquote", 0, 1),
+            raw_entity("expandable_blockquote", 0, 1),
+            pre,
+            custom_emoji,
+            text_link,
+            raw_entity("text_link", 0, 1),
+            raw_entity("new_entity", 0, 1),
+        ];
+
+        let kinds: Vec<MessageEntityKind> = into_entities(text, Some(raw_entities)).unwrap().into_iter().map(|entity| entity.kind).collect();
+
+        match kinds.as_slice() {
+            [MessageEntityKind::Underline,
+             MessageEntityKind::Strikethrough,
+             MessageEntityKind::Spoiler,
+             MessageEntityKind::Blockquote,
+             MessageEntityKind::ExpandableBlockquote,
+             MessageEntityKind::Pre { language: Some(language) },
+             MessageEntityKind::CustomEmoji { custom_emoji_id },
+             MessageEntityKind::TextLink { url },
+             MessageEntityKind::Unknown { typ: broken_link },
+             MessageEntityKind::Unknown { typ: unknown }] => {
+                assert_eq!(language, "rust");
+                assert_eq!(custom_emoji_id, "42");
+                assert_eq!(url, "https://telegram.org");
+                assert_eq!(broken_link, "text_link");
+                assert_eq!(unknown, "new_entity");
+            }
+            kinds =>
+                panic!("Unexpected entity kinds: {:?}", kinds)
+        }
+    }
+}
@@ -5,6 +5,12 @@ pub use self::user::*;
 pub use self::update::*;
 pub use self::queries::*;
 pub use self::file::*;
+pub use self::edited_message::*;
+pub use self::message_entity::*;
+pub use self::album::*;
+pub use self::chat_member::*;
+pub use self::chat_invite_link::*;
+pub use self::reaction::*;
 
 mod message;
 mod chat;
@@ -14,4 +20,10 @@ pub(crate) mod raw;
 mod update;
 mod queries;
 mod file;
+mod edited_message;
+mod message_entity;
+mod album;
+mod chat_member;
+mod chat_invite_link;
+mod reaction;
 
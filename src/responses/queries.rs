@@ -1,8 +1,11 @@
 use responses::user::User;
 use responses::message::Message;
 use super::raw::queries;
-use error::UnexpectedResponse;
+use error::{UnexpectedResponse, CallbackDataError};
 use try_from::TryFrom;
+use callback_data::{self, CallbackDataStore};
+use serde::de::DeserializeOwned;
+use requests::{ChatId, MessageTarget};
 
 #[derive(Debug, Clone)]
 pub struct CallbackQuery {
@@ -37,4 +40,38 @@ impl TryFrom<queries::CallbackQuery> for CallbackQuery {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+impl CallbackQuery {
+    pub fn decode_data<T: DeserializeOwned>(&self) -> Result<T, CallbackDataError> {
+        match self.data {
+            Some(ref data) =>
+                callback_data::decode(data),
+            None =>
+                Err(CallbackDataError::Missing)
+        }
+    }
+
+    pub fn decode_data_with_store<T, S>(&self, store: &S) -> Result<T, CallbackDataError>
+        where T: DeserializeOwned,
+              S: CallbackDataStore + ?Sized {
+        match self.data {
+            Some(ref data) =>
+                callback_data::decode_with_store(data, store),
+            None =>
+                Err(CallbackDataError::Missing)
+        }
+    }
+
+    /// Message that carried the pressed keyboard, `None` if telegram sent neither the message nor `inline_message_id`.
+    pub fn message_target(&self) -> Option<MessageTarget> {
+        match (&self.message, &self.inline_message_id) {
+            (Some(message), _) =>
+                Some(MessageTarget::Chat { chat_id: ChatId::Id(message.chat_id()), message_id: message.id }),
+            (None, Some(inline_message_id)) =>
+                Some(MessageTarget::Inline { inline_message_id: inline_message_id.clone() }),
+            (None, None) =>
+                None
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use responses::raw::message::Message;
+use responses::raw::user::User;
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct Chat {
@@ -15,11 +16,67 @@ pub struct Chat {
     pub invite_link: Option<String>,
     pub pinned_message: Option<Box<Message>>,
     pub sticker_set_name: Option<String>,
-    pub can_set_sticker_set: Option<bool>
+    pub can_set_sticker_set: Option<bool>,
+    pub bio: Option<String>,
+    pub permissions: Option<ChatPermissions>,
+    pub slow_mode_delay: Option<i64>,
+    pub linked_chat_id: Option<i64>,
+    pub is_forum: Option<bool>
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ChatPhoto {
     pub small_file_id: String,
     pub big_file_id: String
-}
\ No newline at end of file
+}
+
+/// Missing permissions are denied.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ChatPermissions {
+    pub can_send_messages: bool,
+    pub can_send_audios: bool,
+    pub can_send_documents: bool,
+    pub can_send_photos: bool,
+    pub can_send_videos: bool,
+    pub can_send_video_notes: bool,
+    pub can_send_voice_notes: bool,
+    pub can_send_polls: bool,
+    pub can_send_other_messages: bool,
+    pub can_add_web_page_previews: bool,
+    pub can_change_info: bool,
+    pub can_invite_users: bool,
+    pub can_pin_messages: bool,
+    pub can_manage_topics: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ForumTopic {
+    pub message_thread_id: i64,
+    pub name: String,
+    pub icon_color: i64,
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatInviteLink {
+    pub invite_link: String,
+    pub creator: User,
+    pub creates_join_request: bool,
+    pub is_primary: bool,
+    pub is_revoked: bool,
+    pub name: Option<String>,
+    pub expire_date: Option<i64>,
+    pub member_limit: Option<i64>,
+    pub pending_join_request_count: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatJoinRequest {
+    pub chat: Chat,
+    pub from: User,
+    pub user_chat_id: i64,
+    pub date: i64,
+    pub bio: Option<String>,
+    pub invite_link: Option<ChatInviteLink>,
+}
@@ -0,0 +1,49 @@
+use responses::raw::chat::{Chat, ChatInviteLink};
+use responses::raw::user::User;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatMember {
+    pub status: String,
+    pub user: User,
+    pub custom_title: Option<String>,
+    pub is_anonymous: Option<bool>,
+    pub can_be_edited: Option<bool>,
+    pub can_manage_chat: Option<bool>,
+    pub can_delete_messages: Option<bool>,
+    pub can_manage_video_chats: Option<bool>,
+    pub can_restrict_members: Option<bool>,
+    pub can_promote_members: Option<bool>,
+    pub can_change_info: Option<bool>,
+    pub can_invite_users: Option<bool>,
+    pub can_post_messages: Option<bool>,
+    pub can_edit_messages: Option<bool>,
+    pub can_pin_messages: Option<bool>,
+    pub can_post_stories: Option<bool>,
+    pub can_edit_stories: Option<bool>,
+    pub can_delete_stories: Option<bool>,
+    pub can_manage_topics: Option<bool>,
+    pub is_member: Option<bool>,
+    pub can_send_messages: Option<bool>,
+    pub can_send_audios: Option<bool>,
+    pub can_send_documents: Option<bool>,
+    pub can_send_photos: Option<bool>,
+    pub can_send_videos: Option<bool>,
+    pub can_send_video_notes: Option<bool>,
+    pub can_send_voice_notes: Option<bool>,
+    pub can_send_polls: Option<bool>,
+    pub can_send_other_messages: Option<bool>,
+    pub can_add_web_page_previews: Option<bool>,
+    pub until_date: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatMemberUpdated {
+    pub chat: Chat,
+    pub from: User,
+    pub date: i64,
+    pub old_chat_member: ChatMember,
+    pub new_chat_member: ChatMember,
+    pub invite_link: Option<ChatInviteLink>,
+    pub via_join_request: Option<bool>,
+    pub via_chat_folder_invite_link: Option<bool>,
+}
@@ -46,7 +46,22 @@ pub struct Message {
     pub invoice: Option<Invoice>,
     pub successful_payment: Option<SuccessfulPayment>,
     pub connected_website: Option<String>,
-    pub passport_data: Option<PassportData>
+    pub passport_data: Option<PassportData>,
+    pub message_thread_id: Option<i64>,
+    pub is_topic_message: Option<bool>,
+    pub forum_topic_created: Option<ForumTopicCreated>,
+    pub forum_topic_edited: Option<ForumTopicEdited>,
+    pub forum_topic_closed: Option<ForumTopicClosed>,
+    pub forum_topic_reopened: Option<ForumTopicReopened>,
+    pub general_forum_topic_hidden: Option<GeneralForumTopicHidden>,
+    pub general_forum_topic_unhidden: Option<GeneralForumTopicUnhidden>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum EditResult {
+    Message(Box<Message>),
+    Inline(bool),
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -57,6 +72,8 @@ pub struct MessageEntity {
     pub length: i64,
     pub url: Option<String>,
     pub user: Option<User>,
+    pub language: Option<String>,
+    pub custom_emoji_id: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -101,6 +118,37 @@ pub struct Game {
     pub animation: Option<Animation>
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct ForumTopicCreated {
+    pub name: String,
+    pub icon_color: i64,
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Fields are set only if they were changed.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ForumTopicEdited {
+    pub name: Option<String>,
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ForumTopicClosed {}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ForumTopicReopened {}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GeneralForumTopicHidden {}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GeneralForumTopicUnhidden {}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageId {
+    pub message_id: i64,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct PhotoSize {
     pub file_id: String,
@@ -6,6 +6,8 @@ pub mod queries;
 pub mod shipping_address;
 pub mod order_info;
 pub mod file;
+pub mod chat_member;
+pub mod reaction;
 
 #[derive(Deserialize, Debug)]
 pub struct TgResponse<T> {
@@ -0,0 +1,39 @@
+use responses::raw::chat::Chat;
+use responses::raw::user::User;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactionType {
+    Emoji { emoji: String },
+    CustomEmoji { custom_emoji_id: String },
+    Paid,
+    /// Reaction types added to telegram after this version.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionCount {
+    #[serde(rename = "type")]
+    pub typ: ReactionType,
+    pub total_count: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageReactionUpdated {
+    pub chat: Chat,
+    pub message_id: i64,
+    pub user: Option<User>,
+    pub actor_chat: Option<Chat>,
+    pub date: i64,
+    pub old_reaction: Vec<ReactionType>,
+    pub new_reaction: Vec<ReactionType>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageReactionCountUpdated {
+    pub chat: Chat,
+    pub message_id: i64,
+    pub date: i64,
+    pub reactions: Vec<ReactionCount>,
+}
@@ -1,5 +1,8 @@
 use responses::raw::message::Message;
-use responses::raw::queries::CallbackQuery;
+use responses::raw::queries::{CallbackQuery, ChosenInlineResult, InlineQuery};
+use responses::raw::chat_member::ChatMemberUpdated;
+use responses::raw::chat::ChatJoinRequest;
+use responses::raw::reaction::{MessageReactionUpdated, MessageReactionCountUpdated};
 
 #[derive(Deserialize, Debug)]
 pub struct Update {
@@ -8,5 +11,12 @@ pub struct Update {
     pub edited_message: Option<Message>,
     pub channel_post: Option<Message>,
     pub edited_channel_post: Option<Message>,
-    pub callback_query: Option<CallbackQuery>
+    pub callback_query: Option<CallbackQuery>,
+    pub inline_query: Option<InlineQuery>,
+    pub chosen_inline_result: Option<ChosenInlineResult>,
+    pub my_chat_member: Option<ChatMemberUpdated>,
+    pub chat_member: Option<ChatMemberUpdated>,
+    pub chat_join_request: Option<ChatJoinRequest>,
+    pub message_reaction: Option<MessageReactionUpdated>,
+    pub message_reaction_count: Option<MessageReactionCountUpdated>
 }
\ No newline at end of file
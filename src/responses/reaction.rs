@@ -0,0 +1,71 @@
+use chrono::prelude::*;
+
+use super::raw;
+use error::UnexpectedResponse;
+use responses::chat::Chat;
+use responses::user::User;
+use try_from::TryFrom;
+
+pub use super::raw::reaction::{ReactionType, ReactionCount};
+
+/// Reaction changed by a user, or by a chat when reacting anonymously.
+#[derive(Clone, Debug)]
+pub struct MessageReactionUpdated {
+    pub chat: Chat,
+    pub message_id: i64,
+    pub user: Option<User>,
+    pub actor_chat: Option<Chat>,
+    pub date: DateTime<Utc>,
+    pub old_reaction: Vec<ReactionType>,
+    pub new_reaction: Vec<ReactionType>,
+}
+
+/// Anonymous reactions are only reported as counts.
+#[derive(Clone, Debug)]
+pub struct MessageReactionCountUpdated {
+    pub chat: Chat,
+    pub message_id: i64,
+    pub date: DateTime<Utc>,
+    pub reactions: Vec<ReactionCount>,
+}
+
+impl TryFrom<raw::reaction::MessageReactionUpdated> for MessageReactionUpdated {
+    type Error = UnexpectedResponse;
+
+    fn try_from(updated: raw::reaction::MessageReactionUpdated) -> Result<Self, UnexpectedResponse> {
+        let actor_chat =
+            match updated.actor_chat {
+                Some(chat) =>
+                    Some(Chat::try_from(chat)?),
+                None =>
+                    None
+            };
+        Ok(MessageReactionUpdated {
+            chat: Chat::try_from(updated.chat)?,
+            message_id: updated.message_id,
+            user: updated.user,
+            actor_chat,
+            date: into_date(updated.date)?,
+            old_reaction: updated.old_reaction,
+            new_reaction: updated.new_reaction,
+        })
+    }
+}
+
+impl TryFrom<raw::reaction::MessageReactionCountUpdated> for MessageReactionCountUpdated {
+    type Error = UnexpectedResponse;
+
+    fn try_from(updated: raw::reaction::MessageReactionCountUpdated) -> Result<Self, UnexpectedResponse> {
+        Ok(MessageReactionCountUpdated {
+            chat: Chat::try_from(updated.chat)?,
+            message_id: updated.message_id,
+            date: into_date(updated.date)?,
+            reactions: updated.reactions,
+        })
+    }
+}
+
+fn into_date(date: i64) -> Result<DateTime<Utc>, UnexpectedResponse> {
+    Utc.timestamp_opt(date, 0).single()
+        .ok_or_else(|| UnexpectedResponse::ConvertError(format!("Invalid reaction update date: {}", date)))
+}
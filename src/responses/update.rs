@@ -4,6 +4,9 @@ use super::raw;
 
 pub use super::raw::queries::{InlineQuery, ChosenInlineResult, ShippingQuery, PreCheckoutQuery};
 use responses::queries::CallbackQuery;
+use responses::chat_member::ChatMemberUpdated;
+use responses::chat_invite_link::ChatJoinRequest;
+use responses::reaction::{MessageReactionUpdated, MessageReactionCountUpdated};
 use try_from::TryFrom;
 
 #[derive(Clone, Debug)]
@@ -21,6 +24,15 @@ pub enum UpdateKind {
     CallbackQuery(CallbackQuery),
     ShippingQuery(ShippingQuery),
     PreCheckoutQuery(PreCheckoutQuery),
+    /// Status of the bot itself changed in a chat.
+    MyChatMember(ChatMemberUpdated),
+    /// Delivered only if `AllowedUpdate::ChatMember` is requested explicitly and the bot is an administrator.
+    ChatMember(ChatMemberUpdated),
+    ChatJoinRequest(ChatJoinRequest),
+    /// Delivered only if `AllowedUpdate::MessageReaction` is requested explicitly and the bot is an administrator.
+    MessageReaction(MessageReactionUpdated),
+    /// Delivered only if `AllowedUpdate::MessageReactionCount` is requested explicitly and the bot is an administrator.
+    MessageReactionCount(MessageReactionCountUpdated),
 }
 
 impl TryFrom<raw::update::Update> for Update {
@@ -45,6 +57,27 @@ impl TryFrom<raw::update::Update> for Update {
                 raw::update::Update { callback_query: Some(query), .. } =>
                     TryFrom::try_from(query).map(UpdateKind::CallbackQuery),
 
+                raw::update::Update { inline_query: Some(query), .. } =>
+                    Ok(UpdateKind::InlineQuery(query)),
+
+                raw::update::Update { chosen_inline_result: Some(result), .. } =>
+                    Ok(UpdateKind::ChosenInlineResult(result)),
+
+                raw::update::Update { my_chat_member: Some(member), .. } =>
+                    TryFrom::try_from(member).map(UpdateKind::MyChatMember),
+
+                raw::update::Update { chat_member: Some(member), .. } =>
+                    TryFrom::try_from(member).map(UpdateKind::ChatMember),
+
+                raw::update::Update { chat_join_request: Some(request), .. } =>
+                    TryFrom::try_from(request).map(UpdateKind::ChatJoinRequest),
+
+                raw::update::Update { message_reaction: Some(reaction), .. } =>
+                    TryFrom::try_from(reaction).map(UpdateKind::MessageReaction),
+
+                raw::update::Update { message_reaction_count: Some(reaction_count), .. } =>
+                    TryFrom::try_from(reaction_count).map(UpdateKind::MessageReactionCount),
+
                 _ =>
                     Err(UnexpectedResponse::Unsupported)
             };
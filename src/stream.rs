@@ -1,12 +1,14 @@
 use std::cmp::max;
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 use futures::Async;
 use futures::Future;
 use futures::Stream;
+use tokio::timer::Delay;
 
 use error::Error;
-use responses::Update;
+use responses::{Album, AlbumUpdate, Message, Update, UpdateKind};
 use std::i64;
 
 pub struct UpdatesStream<Fut, Sender> {
@@ -64,4 +66,199 @@ impl<Fut, Sender> Drop for UpdatesStream<Fut, Sender> {
     fn drop(&mut self) {
         self.is_canceled = true;
     }
-}
\ No newline at end of file
+}
+
+/// Collects messages sharing a `media_group_id` into a single `AlbumUpdate::Album`.
+/// An album is emitted once no new items arrived for `quiet_window`. Channel posts are delivered as `UpdateKind::Message`, so their albums are collected too.
+/// Updates are emitted in the order they were received, so other updates received after an album's first item wait until that album is emitted.
+pub struct AlbumStream<S> {
+    updates: S,
+    quiet_window: Duration,
+    queue: VecDeque<Queued>,
+    is_finished: bool,
+}
+
+enum Queued {
+    Update(AlbumUpdate),
+    Album(PendingAlbum),
+}
+
+struct PendingAlbum {
+    media_group_id: String,
+    messages: Vec<Message>,
+    delay: Delay,
+}
+
+impl<S> AlbumStream<S>
+    where S: Stream<Item=Update, Error=Error> {
+    pub fn new(updates: S, quiet_window: Duration) -> AlbumStream<S> {
+        AlbumStream {
+            updates,
+            quiet_window,
+            queue: VecDeque::new(),
+            is_finished: false,
+        }
+    }
+
+    fn push(&mut self, update: Update) {
+        let media_group_id =
+            match update.kind {
+                UpdateKind::Message(ref message) =>
+                    message.media_group_id().map(String::from),
+                _ =>
+                    None
+            };
+        let (media_group_id, message) =
+            match (media_group_id, update.kind) {
+                (Some(media_group_id), UpdateKind::Message(message)) =>
+                    (media_group_id, message),
+                (_, kind) => {
+                    self.queue.push_back(Queued::Update(AlbumUpdate::Update(Box::new(Update { id: update.id, kind }))));
+                    return;
+                }
+            };
+        let deadline = Instant::now() + self.quiet_window;
+        let album =
+            self.queue.iter_mut().find_map(|queued| match queued {
+                Queued::Album(album) if album.media_group_id == media_group_id =>
+                    Some(album),
+                _ =>
+                    None
+            });
+        match album {
+            Some(album) => {
+                album.messages.push(message);
+                album.delay.reset(deadline);
+            }
+            None =>
+                self.queue.push_back(Queued::Album(PendingAlbum {
+                    media_group_id,
+                    messages: vec![message],
+                    delay: Delay::new(deadline),
+                }))
+        }
+    }
+
+    fn flush_front(&mut self) -> Option<AlbumUpdate> {
+        match self.queue.pop_front()? {
+            Queued::Update(update) =>
+                Some(update),
+            Queued::Album(PendingAlbum { media_group_id, mut messages, .. }) => {
+                messages.sort_by_key(|message| message.id);
+                Some(AlbumUpdate::Album(Album { media_group_id, messages }))
+            }
+        }
+    }
+}
+
+impl<S> Stream for AlbumStream<S>
+    where S: Stream<Item=Update, Error=Error> {
+    type Item = AlbumUpdate;
+    type Error = Error;
+
+    fn poll(&mut self) -> Result<Async<Option<Self::Item>>, Self::Error> {
+        while !self.is_finished {
+            match self.updates.poll()? {
+                Async::Ready(Some(update)) =>
+                    self.push(update),
+                Async::Ready(None) =>
+                    self.is_finished = true,
+                Async::NotReady =>
+                    break,
+            }
+        }
+        let is_ready =
+            match self.queue.front_mut() {
+                Some(Queued::Album(_)) if self.is_finished =>
+                    true,
+                Some(Queued::Album(album)) =>
+                    match album.delay.poll() {
+                        Ok(delay) =>
+                            delay.is_ready(),
+                        // The album is emitted right after the error, otherwise every poll would fail on the same timer.
+                        Err(err) => {
+                            let album = self.flush_front().expect("Album is at the front of the queue");
+                            self.queue.push_front(Queued::Update(album));
+                            return Err(err.into());
+                        }
+                    },
+                Some(Queued::Update(_)) =>
+                    true,
+                None =>
+                    return Ok(if self.is_finished { Async::Ready(None) } else { Async::NotReady }),
+            };
+        if is_ready {
+            Ok(Async::Ready(self.flush_front()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+    use responses::raw;
+    use serde_json;
+    use tokio::runtime::Runtime;
+    use try_from::TryFrom;
+
+    fn update(id: i64, kind: &str, media_group_id: Option<&str>) -> Update {
+        let mut message = serde_json::json!({
+            "message_id": id,
+            "date": 0,
+            "chat": { "id": 1, "type": "channel", "title": "channel" },
+        });
+        match media_group_id {
+            Some(media_group_id) => {
+                message["photo"] = serde_json::json!([{ "file_id": "photo", "width": 1, "height": 1 }]);
+                message["media_group_id"] = serde_json::json!(media_group_id);
+            }
+            None =>
+                message["text"] = serde_json::json!("text"),
+        }
+        let update = serde_json::json!({ "update_id": id, kind: message });
+        let update: raw::update::Update = serde_json::from_value(update).expect("Update is valid json");
+        Update::try_from(update).expect("Update is valid")
+    }
+
+    fn summary(update: &AlbumUpdate) -> Vec<i64> {
+        match update {
+            AlbumUpdate::Update(update) =>
+                vec![update.id],
+            AlbumUpdate::Album(album) =>
+                album.messages.iter().map(|message| message.id).collect(),
+        }
+    }
+
+    #[test]
+    fn keeps_updates_received_after_album_behind_it() {
+        let updates = vec![
+            update(1, "message", Some("a")),
+            update(2, "message", None),
+            update(3, "message", Some("a")),
+            update(4, "channel_post", Some("b")),
+            update(5, "channel_post", Some("b")),
+        ];
+        // The source never ends, so albums are emitted by their timers only.
+        let source = stream::iter_ok(updates).chain(stream::poll_fn(|| Ok(Async::NotReady)));
+        let albums = AlbumStream::new(source, Duration::from_millis(10)).take(3).collect();
+
+        let albums = Runtime::new().expect("Runtime is created").block_on(albums).expect("Albums are collected");
+
+        let summaries: Vec<Vec<i64>> = albums.iter().map(summary).collect();
+        assert_eq!(summaries, vec![vec![1, 3], vec![2], vec![4, 5]]);
+    }
+
+    #[test]
+    fn emits_pending_albums_when_source_ends() {
+        let updates = vec![update(1, "message", Some("a")), update(2, "message", None)];
+        let albums = AlbumStream::new(stream::iter_ok(updates), Duration::from_secs(60)).collect();
+
+        let albums = Runtime::new().expect("Runtime is created").block_on(albums).expect("Albums are collected");
+
+        let summaries: Vec<Vec<i64>> = albums.iter().map(summary).collect();
+        assert_eq!(summaries, vec![vec![1], vec![2]]);
+    }
+}